@@ -0,0 +1,60 @@
+//! Generates the registry of solutions for the in-process runner.
+//! Only active if the `in-process` feature is enabled, see `src/template/registry.rs`.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/bin");
+
+    if env::var_os("CARGO_FEATURE_IN_PROCESS").is_none() {
+        return;
+    }
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    // NOTE: every solution declares a global allocator for heap profiling, so they can not be
+    // compiled into one binary. an empty registry runs all solutions as separate binaries.
+    let is_profiling = env::var_os("CARGO_FEATURE_DHAT_HEAP").is_some();
+
+    let mut bins: Vec<String> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let name = name.strip_suffix(".rs")?;
+            (is_solution_bin(name) && !is_profiling).then(|| name.to_string())
+        })
+        .collect();
+
+    bins.sort_unstable();
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for bin in &bins {
+        let path = bin_dir.join(format!("{bin}.rs"));
+        modules.push_str(&format!(
+            "#[path = {path:?}]\n#[allow(dead_code, unused_imports)]\nmod {bin};\n\n"
+        ));
+        entries.push_str(&format!(
+            "    Solution {{ puzzle: {bin}::PUZZLE, run: {bin}::__run_in_process }},\n"
+        ));
+    }
+
+    let registry = format!(
+        "use advent_of_code::template::registry::Solution;\n\n{modules}pub static SOLUTIONS: &[Solution] = &[\n{entries}];\n"
+    );
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("solutions.rs"), registry).unwrap();
+}
+
+/// Matches binary names like `y2023_d01`.
+fn is_solution_bin(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    bytes.len() == 9
+        && bytes[0] == b'y'
+        && bytes[5..7] == *b"_d"
+        && bytes[1..5].iter().all(u8::is_ascii_digit)
+        && bytes[7..9].iter().all(u8::is_ascii_digit)
+}
@@ -0,0 +1,75 @@
+use advent_of_code::helpers::parse::{Cursor, ParseResult};
+
+advent_of_code::solution!(2023, 2);
+
+struct Game {
+    id: usize,
+    r_max: u32,
+    g_max: u32,
+    b_max: u32,
+}
+
+fn parse_game(c: &mut Cursor) -> ParseResult<Game> {
+    c.tag("Game ")?;
+    let id = c.integer()?;
+    c.delimiter(":")?;
+
+    let mut game = Game {
+        id,
+        r_max: 0,
+        g_max: 0,
+        b_max: 0,
+    };
+
+    c.separated(";", |c| {
+        c.separated(",", |c| {
+            let count = c.integer()?;
+            c.delimiter(" ")?;
+            let color = c.one_of(&[("red", 'r'), ("green", 'g'), ("blue", 'b')])?;
+            match color {
+                'r' => game.r_max = std::cmp::max(count, game.r_max),
+                'g' => game.g_max = std::cmp::max(count, game.g_max),
+                _ => game.b_max = std::cmp::max(count, game.b_max),
+            };
+            Ok(())
+        })
+    })?;
+
+    Ok(game)
+}
+
+fn parse(input: &str) -> ParseResult<Vec<Game>> {
+    Cursor::new(input).lines(parse_game)
+}
+
+pub fn part_one(input: &str) -> ParseResult<usize> {
+    Ok(parse(input)?
+        .into_iter()
+        .filter(|game| game.r_max <= 12 && game.g_max <= 13 && game.b_max <= 14)
+        .map(|game| game.id)
+        .sum())
+}
+
+pub fn part_two(input: &str) -> ParseResult<u32> {
+    Ok(parse(input)?
+        .into_iter()
+        .map(|game| game.r_max * game.g_max * game.b_max)
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_example() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(8));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(2286));
+    }
+}
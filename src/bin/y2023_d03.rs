@@ -2,7 +2,7 @@ use std::collections::VecDeque;
 
 use advent_of_code::helpers::matrix::{Cell, Direction, Matrix};
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(2023, 3);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let matrix: Matrix<char> = Matrix::from(input);
@@ -16,13 +16,13 @@ pub fn part_one(input: &str) -> Option<u32> {
             curr.push(cell.val);
         }
 
-        if (!is_digit || cell.point.col == matrix.cols - 1) && !curr.is_empty() {
+        if (!is_digit || cell.point.x() == matrix.cols - 1) && !curr.is_empty() {
             if matrix
                 .area(
-                    (cell.point.col - curr.len()).saturating_sub(1),
-                    cell.point.col,
-                    cell.point.row.saturating_sub(1),
-                    cell.point.row + 1,
+                    (cell.point.x() - curr.len()).saturating_sub(1),
+                    cell.point.x(),
+                    cell.point.y().saturating_sub(1),
+                    cell.point.y() + 1,
                 )
                 .any(|cell| !cell.val.is_ascii_digit() && cell.val != '.')
             {
@@ -93,9 +93,9 @@ fn walk_digits(matrix: &Matrix<char>, cell: Option<Cell<char>>) -> Option<u32> {
         if walk_left {
             let c = cell
                 .point
-                .col
+                .x()
                 .checked_sub(i)
-                .and_then(|col| matrix.get(cell.point.row, col))
+                .and_then(|col| matrix.get(cell.point.y(), col))
                 .unwrap_or('.');
 
             if c.is_ascii_digit() {
@@ -107,7 +107,7 @@ fn walk_digits(matrix: &Matrix<char>, cell: Option<Cell<char>>) -> Option<u32> {
 
         if walk_right {
             let c = matrix
-                .get(cell.point.row, cell.point.col + i)
+                .get(cell.point.y(), cell.point.x() + i)
                 .unwrap_or('.');
             if c.is_ascii_digit() {
                 curr.push_back(c);
@@ -132,13 +132,13 @@ mod tests {
 
     #[test]
     fn part_one_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(4361));
     }
 
     #[test]
     fn part_two_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(467835));
     }
 }
@@ -1,8 +1,8 @@
-advent_of_code::solution!(4);
+advent_of_code::solution!(2023, 4);
 
 type Card<'a> = (Vec<u32>, Vec<u32>);
 
-fn parse_card(line: &str) -> Option<Card> {
+fn parse_card(line: &str) -> Option<Card<'_>> {
     line.split_once(':')?.1.split_once('|').map(|(a, b)| {
         (
             a.split_ascii_whitespace()
@@ -51,13 +51,13 @@ mod tests {
 
     #[test]
     fn part_one_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(13));
     }
 
     #[test]
     fn part_two_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(30));
     }
 }
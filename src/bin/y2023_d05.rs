@@ -0,0 +1,151 @@
+use advent_of_code::helpers::{
+    intervals::RangeSet,
+    parse::{Cursor, ParseError, ParseResult},
+};
+use itertools::Itertools;
+use std::fmt::Display;
+
+advent_of_code::solution!(2023, 5);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// The input parsed, but has no answer.
+    Unsolvable(&'static str),
+}
+
+impl std::error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse(err) => write!(f, "{err}"),
+            Error::Unsolvable(reason) => f.write_str(reason),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+struct Range {
+    length: usize,
+    source_start: usize,
+    destination_start: usize,
+}
+
+impl Range {
+    fn mapping(&self) -> (std::ops::Range<usize>, usize) {
+        (
+            self.source_start..self.source_start + self.length,
+            self.destination_start,
+        )
+    }
+}
+
+fn parse_map(section: &mut Cursor) -> ParseResult<Vec<Range>> {
+    let mut header = section.line()?;
+    header.take_while(|c| c != ' ');
+    header.tag(" map:")?;
+    header.end()?;
+
+    section.lines(|c| {
+        let [destination_start, source_start, length] = c.array(" ", Cursor::integer)?;
+        Ok(Range {
+            length,
+            source_start,
+            destination_start,
+        })
+    })
+}
+
+fn parse(input: &str) -> ParseResult<(Vec<usize>, Vec<Vec<Range>>)> {
+    let mut cursor = Cursor::new(input);
+    let mut sections = cursor.sections().into_iter();
+
+    let mut seeds_section = sections.next().ok_or_else(|| cursor.error("seeds"))?;
+    seeds_section.tag("seeds:")?;
+    seeds_section.spaces();
+    let seeds: Vec<usize> = seeds_section.separated(" ", Cursor::integer)?;
+    // part two reads the seeds as pairs of a start and a length.
+    if !seeds.len().is_multiple_of(2) {
+        return Err(seeds_section.error("the length of the last seed range"));
+    }
+    seeds_section.end()?;
+
+    let maps = sections
+        .map(|mut section| parse_map(&mut section))
+        .collect::<ParseResult<_>>()?;
+
+    Ok((seeds, maps))
+}
+
+pub fn part_one(input: &str) -> ParseResult<usize> {
+    let (seeds, maps) = parse(input)?;
+    let locations = seeds.into_iter().map(|seed| {
+        maps.iter().fold(seed, |acc, map| {
+            let range = map.iter().find(|range| {
+                acc >= range.source_start && acc < (range.source_start + range.length)
+            });
+
+            match range {
+                Some(range) => acc - range.source_start + range.destination_start,
+                None => acc,
+            }
+        })
+    });
+
+    // NOTE: `parse` reads at least one seed.
+    Ok(locations.min().unwrap_or_default())
+}
+
+pub fn part_two(input: &str) -> Result<usize, Error> {
+    let (seeds, maps) = parse(input)?;
+
+    let seeds: RangeSet<usize> = seeds
+        .chunks_exact(2)
+        .map(|vals| vals[0]..vals[0] + vals[1])
+        .collect();
+
+    maps.iter()
+        .fold(seeds, |acc, map| {
+            acc.map_piecewise(&map.iter().map(Range::mapping).collect_vec())
+        })
+        .min()
+        .ok_or(Error::Unsolvable("every seed range is empty"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_example() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(35));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(46));
+    }
+
+    #[test]
+    fn odd_number_of_seeds() {
+        let result = part_two("seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2\n");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "expecting the length of the last seed range at 1:16, found end of line"
+        );
+    }
+
+    #[test]
+    fn part_two_empty_seed_ranges() {
+        let result = part_two("seeds: 79 0\n\nseed-to-soil map:\n50 98 2\n");
+        assert!(matches!(result, Err(Error::Unsolvable(_))));
+    }
+}
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(2023, 6);
 
 fn simulate(times: &[f64], highscores: &[f64]) -> f64 {
     times
@@ -53,13 +53,13 @@ mod tests {
 
     #[test]
     fn part_one_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(288));
     }
 
     #[test]
     fn part_two_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(71503));
     }
 }
@@ -1,7 +1,7 @@
 use itertools::Itertools;
 use std::cmp::Ordering;
 
-advent_of_code::solution!(7);
+advent_of_code::solution!(2023, 7);
 
 #[derive(PartialEq, Eq, PartialOrd, Ord)]
 enum Hand {
@@ -110,13 +110,13 @@ mod tests {
 
     #[test]
     fn part_one_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(6440));
     }
 
     #[test]
     fn part_two_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(5905));
     }
 }
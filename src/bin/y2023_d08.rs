@@ -1,14 +1,14 @@
 use advent_of_code::helpers::math::least_common_multiple;
 use hashbrown::HashMap;
 
-advent_of_code::solution!(8);
+advent_of_code::solution!(2023, 8);
 
 struct Node<'a> {
     left: &'a str,
     right: &'a str,
 }
 
-fn parse(input: &str) -> Option<(Vec<char>, HashMap<&str, Node>)> {
+fn parse(input: &str) -> Option<(Vec<char>, HashMap<&str, Node<'_>>)> {
     let (instruction_s, graph_s) = input.split_once("\n\n")?;
     let instructions = instruction_s.chars().collect();
 
@@ -68,7 +68,7 @@ pub fn part_two(input: &str) -> Option<usize> {
 
     let mut cycle_lengths = vec![0; current_nodes.len()];
 
-    while cycle_lengths.iter().any(|x| *x == 0) {
+    while cycle_lengths.contains(&0) {
         let instruction = instructions[steps % instructions.len()];
 
         for (i, current) in current_nodes.iter_mut().enumerate() {
@@ -90,7 +90,7 @@ pub fn part_two(input: &str) -> Option<usize> {
         steps += 1;
     }
 
-    Some(least_common_multiple(&cycle_lengths))
+    least_common_multiple(&cycle_lengths)
 }
 
 #[cfg(test)]
@@ -100,7 +100,7 @@ mod tests {
     #[test]
     fn part_one_example() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", PUZZLE, 1,
         ));
         assert_eq!(result, Some(2));
     }
@@ -108,7 +108,7 @@ mod tests {
     #[test]
     fn part_one_example_two() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", PUZZLE, 2,
         ));
         assert_eq!(result, Some(6));
     }
@@ -116,7 +116,7 @@ mod tests {
     #[test]
     fn part_two_example() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 3,
+            "examples", PUZZLE, 3,
         ));
         assert_eq!(result, Some(6));
     }
@@ -1,8 +1,12 @@
-use advent_of_code::helpers::matrix::{Cell, Direction, Matrix, CARDINALS};
-use hashbrown::{HashMap, HashSet};
+use advent_of_code::helpers::{
+    geometry,
+    matrix::{Cell, Direction, Matrix, CARDINALS},
+    point::Point2,
+};
+use hashbrown::HashMap;
 use once_cell::sync::Lazy;
 
-advent_of_code::solution!(10);
+advent_of_code::solution!(2023, 10);
 
 type Pipe = Vec<(Direction, Direction)>;
 
@@ -70,11 +74,11 @@ fn try_loop_from_start(
 
                     let pipe_type = PIPES
                         .iter()
-                        .find(|pipe| pipe.1.iter().any(|p| *p == (from, to)))
+                        .find(|pipe| pipe.1.contains(&(from, to)))
                         .map(|pipe| *pipe.0)
                         .unwrap();
 
-                    let c = matrix.get_mut(cell.point.row, cell.point.col).unwrap();
+                    let c = matrix.get_mut(cell.point.y(), cell.point.x()).unwrap();
                     *c = pipe_type;
 
                     break;
@@ -111,32 +115,12 @@ pub fn part_one(input: &str) -> Option<usize> {
 
 pub fn part_two(input: &str) -> Option<usize> {
     let mut matrix = Matrix::from(input);
-    let pipe: HashSet<Cell> = find_loop(&mut matrix).map(HashSet::from_iter)?;
-
-    Some(
-        matrix
-            .items()
-            .filter(|cell| {
-                if pipe.contains(cell) {
-                    return false;
-                }
-
-                let row = cell.point.row;
-                let mut crosses = 0;
-
-                (0..cell.point.col).try_for_each(|col| {
-                    let nb = matrix.get_cell(row, col)?;
-                    // could check `|LJ` as well
-                    if "|F7".contains(nb.val) && pipe.contains(&nb) {
-                        crosses += 1;
-                    }
-                    Some(())
-                });
+    let pipe: Vec<Point2> = find_loop(&mut matrix)?
+        .into_iter()
+        .map(|cell| Point2::from(cell.point))
+        .collect();
 
-                crosses % 2 != 0
-            })
-            .count(),
-    )
+    Some(geometry::interior_points(&pipe) as usize)
 }
 
 #[cfg(test)]
@@ -146,7 +130,7 @@ mod tests {
     #[test]
     fn part_one_example() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", PUZZLE, 1,
         ));
         assert_eq!(result, Some(4));
     }
@@ -154,7 +138,7 @@ mod tests {
     #[test]
     fn part_one_example_two() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", PUZZLE, 2,
         ));
         assert_eq!(result, Some(8));
     }
@@ -162,7 +146,7 @@ mod tests {
     #[test]
     fn part_two_example_one() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 3,
+            "examples", PUZZLE, 3,
         ));
         assert_eq!(result, Some(4));
     }
@@ -170,7 +154,7 @@ mod tests {
     #[test]
     fn part_two_example_two() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 4,
+            "examples", PUZZLE, 4,
         ));
         assert_eq!(result, Some(4));
     }
@@ -178,7 +162,7 @@ mod tests {
     #[test]
     fn part_two_example_three() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 5,
+            "examples", PUZZLE, 5,
         ));
         assert_eq!(result, Some(8));
     }
@@ -186,7 +170,7 @@ mod tests {
     #[test]
     fn part_two_example_four() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 6,
+            "examples", PUZZLE, 6,
         ));
         assert_eq!(result, Some(10));
     }
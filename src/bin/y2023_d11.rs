@@ -1,38 +1,39 @@
-use advent_of_code::helpers::grid::Point;
+use advent_of_code::helpers::point::Point2;
 use hashbrown::HashSet;
 use itertools::Itertools;
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(2023, 11);
 
 fn solve(input: &str, scaling_factor: i64) -> u64 {
     let scaling_factor = scaling_factor - 1;
 
     let mut x_idx: HashSet<i64> = HashSet::new();
     let mut y_idx: HashSet<i64> = HashSet::new();
-    let mut galaxies: Vec<Point<i64>> = vec![];
+    let mut galaxies: Vec<Point2> = vec![];
 
     input.lines().enumerate().for_each(|(y, l)| {
         for (x, c) in l.chars().enumerate() {
             if c == '#' {
                 x_idx.insert(x as i64);
                 y_idx.insert(y as i64);
-                galaxies.push(Point(x as i64, y as i64));
+                galaxies.push(Point2::new(x as i64, y as i64));
             }
         }
     });
 
     galaxies
         .iter()
-        .map(|Point(x, y)| {
-            let x_scaling = x - x_idx.iter().filter(|&other_x| other_x < x).count() as i64;
-            let y_scaling = y - y_idx.iter().filter(|&other_y| other_y < y).count() as i64;
-            Point(
+        .map(|galaxy| {
+            let (x, y) = (galaxy.x(), galaxy.y());
+            let x_scaling = x - x_idx.iter().filter(|&&other_x| other_x < x).count() as i64;
+            let y_scaling = y - y_idx.iter().filter(|&&other_y| other_y < y).count() as i64;
+            Point2::new(
                 x + (scaling_factor * x_scaling),
                 y + (scaling_factor * y_scaling),
             )
         })
         .combinations(2)
-        .map(|pair| pair[0].distance(&pair[1]))
+        .map(|pair| pair[0].manhattan(&pair[1]) as u64)
         .sum()
 }
 
@@ -50,19 +51,22 @@ mod tests {
 
     #[test]
     fn example_one() {
-        let result = solve(&advent_of_code::template::read_file("examples", DAY), 2);
+        let result = solve(&advent_of_code::template::read_file("examples", PUZZLE), 2);
         assert_eq!(result, 374);
     }
 
     #[test]
     fn example_two() {
-        let result = solve(&advent_of_code::template::read_file("examples", DAY), 10);
+        let result = solve(&advent_of_code::template::read_file("examples", PUZZLE), 10);
         assert_eq!(result, 1030);
     }
 
     #[test]
     fn example_three() {
-        let result = solve(&advent_of_code::template::read_file("examples", DAY), 100);
+        let result = solve(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            100,
+        );
         assert_eq!(result, 8410);
     }
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(13);
+advent_of_code::solution!(2023, 13);
 
 fn transpose(input: &str) -> Vec<String> {
     let lines: Vec<&str> = input.lines().collect();
@@ -100,13 +100,13 @@ mod tests {
 
     #[test]
     fn part_one_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(405));
     }
 
     #[test]
     fn part_two_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(400));
     }
 }
@@ -1,10 +1,9 @@
-use advent_of_code::helpers::matrix::Matrix;
-use hashbrown::HashSet;
+use advent_of_code::helpers::{cycle, matrix::Matrix};
 
-advent_of_code::solution!(14);
+advent_of_code::solution!(2023, 14);
 
 fn apply_cycle(matrix: &mut Matrix) {
-    matrix.cells.iter_mut().for_each(|row| {
+    matrix.iter_rows_mut().for_each(|row| {
         let mut latest_pos = 0;
         for i in 0..row.len() {
             let c = row[i];
@@ -18,10 +17,18 @@ fn apply_cycle(matrix: &mut Matrix) {
     });
 }
 
+/// Tilts the platform in all four directions, ending up in the orientation it started in.
+fn spin_cycle(matrix: &mut Matrix) {
+    for _ in 0..4 {
+        apply_cycle(matrix);
+        matrix.rotate_counterclockwise();
+    }
+}
+
 fn count_load(matrix: &Matrix) -> usize {
     let mut total = 0;
 
-    for row in matrix.cells.iter() {
+    for row in matrix.iter_rows() {
         for (i, &c) in row.iter().rev().enumerate() {
             if c == 'O' {
                 total += i + 1;
@@ -43,44 +50,7 @@ pub fn part_two(input: &str) -> Option<usize> {
     let mut matrix = Matrix::from(input);
     matrix.transpose();
 
-    let mut seen = HashSet::new();
-
-    let mut i = 0;
-
-    let mut cycle_start_index: usize = 0;
-    let mut cycle_start: Option<Vec<Vec<char>>> = None;
-
-    while i < 1_000_000_000 {
-        apply_cycle(&mut matrix);
-        matrix.rotate_counterclockwise();
-
-        apply_cycle(&mut matrix);
-        matrix.rotate_counterclockwise();
-
-        apply_cycle(&mut matrix);
-        matrix.rotate_counterclockwise();
-
-        apply_cycle(&mut matrix);
-
-        if seen.contains(&matrix.cells) {
-            matrix.rotate_counterclockwise();
-
-            if cycle_start.as_ref().is_some_and(|x| x == &matrix.cells) {
-                let cycle_length = i - cycle_start_index;
-                i += 1 + ((1_000_000_000 - i) / cycle_length) * cycle_length;
-                continue;
-            } else if cycle_start.as_ref().is_none() {
-                cycle_start = Some(matrix.cells.clone());
-                cycle_start_index = i;
-            }
-        } else {
-            seen.insert(matrix.cells.clone());
-            matrix.rotate_counterclockwise();
-        }
-
-        i += 1;
-    }
-
+    let matrix = cycle::fast_forward(matrix, 1_000_000_000, spin_cycle, Matrix::clone);
     Some(count_load(&matrix))
 }
 
@@ -90,13 +60,13 @@ mod tests {
 
     #[test]
     fn part_one_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(136));
     }
 
     #[test]
     fn part_two_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(64));
     }
 }
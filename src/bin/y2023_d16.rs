@@ -1,12 +1,11 @@
-use advent_of_code::helpers::matrix::{Cell, Direction, Matrix, Point};
+use advent_of_code::helpers::{
+    matrix::{Cell, Direction, Matrix},
+    point::Point2,
+    search::{flood_fill, Heading},
+};
 use hashbrown::HashSet;
 
-advent_of_code::solution!(16);
-
-#[derive(PartialEq, Eq, Hash)]
-struct BeamLog(Cell, Direction);
-
-struct Beam(Cell, Direction);
+advent_of_code::solution!(2023, 16);
 
 fn get_beam_directions(cell: &Cell, current_dir: Direction) -> Vec<Direction> {
     match cell.val {
@@ -40,44 +39,18 @@ fn get_beam_directions(cell: &Cell, current_dir: Direction) -> Vec<Direction> {
 }
 
 fn trace_beam(matrix: &Matrix, cell: Cell, direction: Direction) -> usize {
-    let mut beams: Vec<Beam> = get_beam_directions(&cell, direction)
-        .into_iter()
-        .map(|dir| Beam(cell, dir))
-        .collect();
-
-    let mut visited: HashSet<BeamLog> = HashSet::new();
-    visited.insert(BeamLog(cell, direction));
-
-    while !beams.is_empty() {
-        let mut next_beams: Vec<Beam> = vec![];
-
-        for beam in beams.iter() {
-            let next = matrix.neighbour(&beam.0, &beam.1);
-
-            if let Some(next) = next {
-                let log = BeamLog(next, beam.1);
-                if !visited.contains(&log) {
-                    visited.insert(log);
-
-                    next_beams.extend(
-                        get_beam_directions(&next, beam.1)
-                            .into_iter()
-                            .map(|dir| Beam(next, dir)),
-                    );
-                }
-            }
-        }
-
-        beams = next_beams;
-    }
-
-    let mut set: HashSet<Point> = HashSet::new();
-
-    visited.into_iter().for_each(|p| {
-        set.insert(p.0.point);
+    let energized = flood_fill([Heading::new(cell, direction)], |beam| {
+        let beam = *beam;
+        get_beam_directions(&beam.cell, beam.dir)
+            .into_iter()
+            .filter_map(move |dir| beam.advance(matrix, dir))
     });
 
-    set.len()
+    energized
+        .into_iter()
+        .map(|beam| beam.cell.point)
+        .collect::<HashSet<Point2<usize>>>()
+        .len()
 }
 
 pub fn part_one(input: &str) -> Option<usize> {
@@ -120,13 +93,13 @@ mod tests {
 
     #[test]
     fn part_one_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(46));
     }
 
     #[test]
     fn part_two_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(51));
     }
 }
@@ -0,0 +1,81 @@
+use advent_of_code::helpers::{
+    matrix::{Cell, Matrix, CARDINALS},
+    search::{astar, Heading},
+};
+
+advent_of_code::solution!(2023, 17);
+
+/// A crucible with the number of blocks it has moved in its current direction.
+type State = (Heading<u32>, u32);
+
+fn shortest_path(
+    matrix: &Matrix<u32>,
+    start: Cell<u32>,
+    end: Cell<u32>,
+    min_steps: u32,
+    max_steps: u32,
+) -> Option<u32> {
+    let starts = CARDINALS.map(|dir| (Heading::new(start, dir), 0));
+
+    let successors = |state: &State| {
+        let (heading, steps) = *state;
+        heading.moves(matrix).filter_map(move |next| {
+            if next.dir == heading.dir && steps < max_steps {
+                Some(((next, steps + 1), next.cell.val))
+            } else if next.dir != heading.dir && steps >= min_steps {
+                Some(((next, 1), next.cell.val))
+            } else {
+                None
+            }
+        })
+    };
+
+    // every block costs at least 1, so the manhattan distance never overestimates.
+    let heuristic = |(heading, _): &State| end.point.manhattan(&heading.cell.point) as u32;
+
+    astar(starts, successors, heuristic, |(heading, _)| {
+        heading.cell == end
+    })
+    .map(|path| path.cost)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let matrix: Matrix<u32> = Matrix::from(input);
+
+    shortest_path(
+        &matrix,
+        matrix.get_cell(0, 0)?,
+        matrix.get_cell(matrix.rows - 1, matrix.cols - 1)?,
+        0,
+        3,
+    )
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let matrix: Matrix<u32> = Matrix::from(input);
+
+    shortest_path(
+        &matrix,
+        matrix.get_cell(0, 0)?,
+        matrix.get_cell(matrix.rows - 1, matrix.cols - 1)?,
+        4,
+        10,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_example() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(102));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(94));
+    }
+}
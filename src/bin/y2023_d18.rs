@@ -1,31 +1,11 @@
-use advent_of_code::helpers::grid::Point;
+use advent_of_code::helpers::{geometry, matrix::Direction};
 use itertools::Itertools;
 
-advent_of_code::solution!(18);
+advent_of_code::solution!(2023, 18);
 
-fn solve(instructions: &[(char, i64)]) -> i64 {
-    let mut current = Point(0, 0);
-    let mut border = 0;
-    let mut shoelace = (0, 0);
-
-    instructions.iter().for_each(|(dir, amount)| {
-        let next = match dir {
-            'U' => Point(current.0, current.1 - amount),
-            'D' => Point(current.0, current.1 + amount),
-            'L' => Point(current.0 - amount, current.1),
-            'R' => Point(current.0 + amount, current.1),
-            _ => unreachable!(),
-        };
-
-        border += amount;
-        shoelace.0 += current.0 * next.1;
-        shoelace.1 += current.1 * next.0;
-        current = next;
-    });
-
-    let area = (shoelace.0.abs_diff(shoelace.1) / 2) as i64;
-    let interior = 1 + area - border / 2;
-    interior + border
+fn solve(instructions: &[(Direction, i64)]) -> i64 {
+    let lagoon = geometry::polygon_from_moves(instructions.iter().copied());
+    geometry::interior_points(&lagoon) + geometry::perimeter(&lagoon)
 }
 
 pub fn part_one(input: &str) -> Option<i64> {
@@ -33,7 +13,13 @@ pub fn part_one(input: &str) -> Option<i64> {
         .lines()
         .filter_map(|l| {
             let mut parts = l.split_ascii_whitespace();
-            let dir = parts.next().and_then(|p| p.chars().next())?;
+            let dir = match parts.next()? {
+                "U" => Direction::N,
+                "D" => Direction::S,
+                "L" => Direction::W,
+                "R" => Direction::E,
+                _ => unreachable!(),
+            };
             let amount: i64 = parts.next().and_then(|p| p.parse().ok())?;
             Some((dir, amount))
         })
@@ -51,10 +37,10 @@ pub fn part_two(input: &str) -> Option<i64> {
             let amount = u32::from_str_radix(&hex[0..5], 16).ok()?;
 
             let dir = match &hex[5..].chars().next()? {
-                '0' => 'R',
-                '1' => 'D',
-                '2' => 'L',
-                '3' => 'U',
+                '0' => Direction::E,
+                '1' => Direction::S,
+                '2' => Direction::W,
+                '3' => Direction::N,
                 _ => unreachable!(),
             };
 
@@ -71,13 +57,13 @@ mod tests {
 
     #[test]
     fn part_one_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(62));
     }
 
     #[test]
     fn part_two_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(952408144115));
     }
 }
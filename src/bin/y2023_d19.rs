@@ -0,0 +1,295 @@
+use advent_of_code::helpers::{
+    intervals::RangeSet,
+    parse::{Cursor, ParseResult},
+};
+use hashbrown::HashMap;
+
+advent_of_code::solution!(2023, 19);
+
+struct Workflow<'a> {
+    rules: Vec<Rule<'a>>,
+}
+
+struct Rule<'a> {
+    matches: char,
+    value: u64,
+    comparator: char,
+    destination_pass: &'a str,
+    destination_deny: Option<&'a str>,
+}
+
+struct Part {
+    x: u64,
+    m: u64,
+    a: u64,
+    s: u64,
+}
+
+#[derive(Clone)]
+struct PartRange {
+    x: RangeSet<u64>,
+    m: RangeSet<u64>,
+    a: RangeSet<u64>,
+    s: RangeSet<u64>,
+}
+
+impl Default for PartRange {
+    fn default() -> Self {
+        let ratings = RangeSet::from(1..=4000);
+
+        Self {
+            x: ratings.clone(),
+            m: ratings.clone(),
+            a: ratings.clone(),
+            s: ratings,
+        }
+    }
+}
+
+impl PartRange {
+    fn get_mut(&mut self, category: char) -> &mut RangeSet<u64> {
+        match category {
+            'x' => &mut self.x,
+            'm' => &mut self.m,
+            'a' => &mut self.a,
+            's' => &mut self.s,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Splits the ranges into the parts that pass and fail `rule`.
+    fn split(&self, rule: &Rule) -> (Self, Self) {
+        let mut pass = self.clone();
+        let mut deny = self.clone();
+
+        let ratings = pass.get_mut(rule.matches);
+
+        let (passed, denied) = match rule.comparator {
+            '>' => {
+                let (below, above) = ratings.split_at(rule.value + 1);
+                (above, below)
+            }
+            '<' => ratings.split_at(rule.value),
+            _ => unreachable!(),
+        };
+
+        *ratings = passed;
+        *deny.get_mut(rule.matches) = denied;
+
+        (pass, deny)
+    }
+
+    fn count(&self) -> u64 {
+        self.x.len() * self.m.len() * self.a.len() * self.s.len()
+    }
+}
+
+#[derive(Clone)]
+struct State<'a> {
+    range: PartRange,
+    path: Vec<&'a str>,
+}
+
+impl<'a> Default for State<'a> {
+    fn default() -> Self {
+        Self {
+            range: Default::default(),
+            path: vec!["in"],
+        }
+    }
+}
+
+fn parse_workflow<'a>(c: &mut Cursor<'a>) -> ParseResult<(&'a str, Workflow<'a>)> {
+    let id = c.word()?;
+    c.tag("{")?;
+
+    let mut rules: Vec<Rule> = vec![];
+
+    loop {
+        let start = *c;
+        let word = c.word()?;
+
+        let Ok(comparator) = c.one_of(&[("<", '<'), (">", '>')]) else {
+            // the last entry only names where the remaining parts go.
+            let last = rules.last_mut().ok_or_else(|| c.error("'<' or '>'"))?;
+            last.destination_deny = Some(word);
+            break;
+        };
+
+        let matches = match word {
+            "x" | "m" | "a" | "s" => word.chars().next().unwrap(),
+            _ => return Err(start.error("one of 'x', 'm', 'a', 's'")),
+        };
+
+        let value = c.integer()?;
+        c.tag(":")?;
+        let destination_pass = c.word()?;
+        c.tag(",")?;
+
+        rules.push(Rule {
+            matches,
+            value,
+            comparator,
+            destination_pass,
+            destination_deny: None,
+        });
+    }
+
+    c.tag("}")?;
+    Ok((id, Workflow { rules }))
+}
+
+fn parse_part(c: &mut Cursor) -> ParseResult<Part> {
+    let start = *c;
+    c.tag("{")?;
+    let ratings: HashMap<&str, u64> = c
+        .key_values(",", "=", Cursor::integer)?
+        .into_iter()
+        .collect();
+    c.tag("}")?;
+
+    let rating = |category| {
+        ratings
+            .get(category)
+            .copied()
+            .ok_or_else(|| start.error(format!("a rating for '{category}'")))
+    };
+
+    Ok(Part {
+        x: rating("x")?,
+        m: rating("m")?,
+        a: rating("a")?,
+        s: rating("s")?,
+    })
+}
+
+type Workflows<'a> = HashMap<&'a str, Workflow<'a>>;
+
+fn parse(input: &str) -> ParseResult<(Workflows<'_>, Vec<Part>)> {
+    let mut cursor = Cursor::new(input);
+
+    let [mut workflows, mut parts] = <[Cursor; 2]>::try_from(cursor.sections())
+        .map_err(|_| cursor.error("workflows and parts separated by a blank line"))?;
+
+    Ok((
+        workflows.lines(parse_workflow)?.into_iter().collect(),
+        parts.lines(parse_part)?,
+    ))
+}
+
+fn apply_rule(rule: &Rule, value: u64) -> bool {
+    match rule.comparator {
+        '>' => value > rule.value,
+        '<' => value < rule.value,
+        _ => unreachable!(),
+    }
+}
+
+fn apply_workflow<'a: 'b, 'b>(workflow: &'a Workflow<'a>, part: &'a Part) -> &'b str {
+    let mut ret = "R";
+
+    for rule in &workflow.rules {
+        let pass = match rule.matches {
+            'x' => apply_rule(rule, part.x),
+            'm' => apply_rule(rule, part.m),
+            'a' => apply_rule(rule, part.a),
+            's' => apply_rule(rule, part.s),
+            _ => unreachable!(),
+        };
+
+        if pass {
+            ret = rule.destination_pass;
+            break;
+        } else if rule.destination_deny.is_some() {
+            ret = rule.destination_deny.unwrap();
+            break;
+        }
+    }
+
+    ret
+}
+
+pub fn part_one(input: &str) -> ParseResult<u64> {
+    parse(input).map(|(workflows, parts)| {
+        parts
+            .into_iter()
+            .filter_map(|part| {
+                let mut current = "in";
+
+                while current != "A" && current != "R" {
+                    current = apply_workflow(workflows.get(current).unwrap(), &part);
+                }
+
+                if current == "A" {
+                    Some(part.x + part.m + part.a + part.s)
+                } else {
+                    None
+                }
+            })
+            .sum()
+    })
+}
+
+fn resolve_state<'a>(workflows: &'a Workflows, state: State<'a>) -> Vec<State<'a>> {
+    let mut next_states = vec![];
+
+    let current = state.path.last().expect("empty paths are not allowed.");
+
+    // we are at the end.
+    if *current == "A" || *current == "R" {
+        return vec![state];
+    }
+
+    let workflow = workflows.get(current).expect("undefined workflow");
+
+    let mut current_state = state.clone();
+
+    for rule in &workflow.rules {
+        let (pass_range, deny_range) = current_state.range.split(rule);
+
+        let mut pass_state = current_state.clone();
+        pass_state.range = pass_range;
+        pass_state.path.push(rule.destination_pass);
+        next_states.extend(resolve_state(workflows, pass_state));
+
+        current_state.range = deny_range;
+        if let Some(deny) = rule.destination_deny {
+            current_state.path.push(deny);
+            next_states.extend(resolve_state(workflows, current_state.clone()));
+        }
+    }
+
+    next_states
+}
+
+pub fn part_two(input: &str) -> ParseResult<u64> {
+    parse(input).map(|(workflows, _)| {
+        resolve_state(&workflows, State::default())
+            .into_iter()
+            .flat_map(|state| {
+                if state.path.last().unwrap() == &"A" {
+                    Some(state.range.count())
+                } else {
+                    None
+                }
+            })
+            .sum()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_example() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(19114));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(167409079868000));
+    }
+}
@@ -1,7 +1,7 @@
-use advent_of_code::helpers::math::least_common_multiple;
-use hashbrown::HashMap;
+use advent_of_code::helpers::{cycle, math::chinese_remainder, search::flood_fill};
+use hashbrown::{HashMap, HashSet};
 
-advent_of_code::solution!(20);
+advent_of_code::solution!(2023, 20);
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 enum PulseType {
@@ -9,7 +9,7 @@ enum PulseType {
     High,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 enum NodeState<'a> {
     Broadcast,
     FlipFlop(bool),
@@ -24,18 +24,35 @@ struct Node<'a> {
 
 type Nodes<'a> = HashMap<&'a str, Node<'a>>;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct QueueItem<'a>(&'a str, &'a str, PulseType);
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 struct States<'a> {
-    cycle_count: usize,
-    cycles: HashMap<&'a str, usize>,
+    /// conjunctions that sent a high pulse since the button was last pressed.
+    high_senders: HashSet<&'a str>,
     data: HashMap<&'a str, NodeState<'a>>,
     queue: Vec<QueueItem<'a>>,
 }
 
 impl<'a> States<'a> {
+    fn press_button(&mut self, nodes: &'a Nodes) -> (u64, u64) {
+        let mut pulse_count_low = 0;
+        let mut pulse_count_high = 0;
+
+        self.high_senders.clear();
+        self.queue
+            .push(QueueItem("button", "broadcaster", PulseType::Low));
+
+        while !self.queue.is_empty() {
+            let (low, high) = self.drain_queue(nodes);
+            pulse_count_low += low;
+            pulse_count_high += high;
+        }
+
+        (pulse_count_low, pulse_count_high)
+    }
+
     fn drain_queue(&mut self, nodes: &'a Nodes) -> (u64, u64) {
         let mut pulse_count_low = 0;
         let mut pulse_count_high = 0;
@@ -57,11 +74,6 @@ impl<'a> States<'a> {
         target: &'a str,
         pulse_type: PulseType,
     ) -> (u64, u64) {
-        // try turning it on and off again.
-        if sender == "button" {
-            self.cycle_count += 1;
-        }
-
         if let Some(node) = nodes.get(target) {
             let node_state = self.data.get_mut(target).unwrap();
 
@@ -94,7 +106,7 @@ impl<'a> States<'a> {
                     let pulse = if current.values().all(|v| *v == PulseType::High) {
                         PulseType::Low
                     } else {
-                        self.cycles.insert(target, self.cycle_count);
+                        self.high_senders.insert(target);
                         PulseType::High
                     };
 
@@ -113,7 +125,7 @@ impl<'a> States<'a> {
     }
 }
 
-fn parse(input: &str) -> (Nodes, States) {
+fn parse(input: &str) -> (Nodes<'_>, States<'_>) {
     let (nodes, mut states) = input
         .lines()
         .filter_map(|l| {
@@ -167,22 +179,16 @@ pub fn part_one(input: &str) -> Option<u64> {
     let mut pulse_count_high = 0;
 
     for _ in 0..1000 {
-        states
-            .queue
-            .push(QueueItem("button", "broadcaster", PulseType::Low));
-
-        while !states.queue.is_empty() {
-            let (low, high) = states.drain_queue(&nodes);
-            pulse_count_low += low;
-            pulse_count_high += high;
-        }
+        let (low, high) = states.press_button(&nodes);
+        pulse_count_low += low;
+        pulse_count_high += high;
     }
 
     Some(pulse_count_low * pulse_count_high)
 }
 
 pub fn part_two(input: &str) -> Option<usize> {
-    let (nodes, mut states) = parse(input);
+    let (nodes, states) = parse(input);
 
     let target = "rx";
 
@@ -196,28 +202,60 @@ pub fn part_two(input: &str) -> Option<usize> {
         .map(|n| n.id)
         .collect();
 
-    for _ in 0..usize::MAX {
-        states
-            .queue
-            .push(QueueItem("button", "broadcaster", PulseType::Low));
+    // INVARIANT: every grandparent is driven by a separate counter of flip-flops, which repeats
+    // its states independently of the others and makes it send a high pulse once per cycle.
+    let congruences: Vec<(i64, i64)> = grandparents
+        .iter()
+        .map(|grandparent| {
+            let mut flip_flops: Vec<&str> = flood_fill([*grandparent], |id| {
+                nodes
+                    .values()
+                    .filter(|n| n.destinations.contains(id))
+                    .map(|n| n.id)
+                    .collect::<Vec<_>>()
+            })
+            .into_iter()
+            .filter(|id| matches!(states.data.get(id), Some(NodeState::FlipFlop(_))))
+            .collect();
+            flip_flops.sort_unstable();
+
+            let mut presses = 0;
+            let mut last_high = 0;
+
+            let (mu, lambda) = cycle::find_cycle(
+                states.clone(),
+                |states| {
+                    states.press_button(&nodes);
+                    presses += 1;
+                    if states.high_senders.contains(grandparent) {
+                        last_high = presses;
+                    }
+                },
+                |states| {
+                    flip_flops
+                        .iter()
+                        .map(|id| matches!(states.data[id], NodeState::FlipFlop(true)))
+                        .collect::<Vec<bool>>()
+                },
+            );
+
+            // the press that sent the high pulse has to be part of the cycle to repeat.
+            (last_high > mu).then_some((last_high as i64, lambda as i64))
+        })
+        .collect::<Option<_>>()?;
 
-        while !states.queue.is_empty() {
-            states.drain_queue(&nodes);
-        }
+    first_common_press(&congruences)
+}
 
-        let grandparent_cycles: Vec<usize> = states
-            .cycles
-            .iter()
-            .filter(|(key, _)| grandparents.contains(*key))
-            .map(|(_, val)| *val)
-            .collect();
+/// Finds the first press on which every `(press, period)` cycle sends a high pulse, counting
+/// from the first press of each cycle.
+fn first_common_press(congruences: &[(i64, i64)]) -> Option<usize> {
+    let (press, period) = chinese_remainder(congruences)?;
+    let first = congruences.iter().map(|(press, _)| *press).max()?;
 
-        if grandparent_cycles.len() == grandparents.len() {
-            return Some(least_common_multiple(&grandparent_cycles));
-        }
-    }
+    let skipped_periods = (first - press + period - 1).div_euclid(period).max(0);
 
-    None
+    Some((press + skipped_periods * period) as usize)
 }
 
 #[cfg(test)]
@@ -227,7 +265,7 @@ mod tests {
     #[test]
     fn part_one_example_one() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples", PUZZLE, 1,
         ));
         assert_eq!(result, Some(32000000));
     }
@@ -235,8 +273,32 @@ mod tests {
     #[test]
     fn part_one_example_two() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", PUZZLE, 2,
         ));
         assert_eq!(result, Some(11687500));
     }
+
+    #[test]
+    fn part_two_counters() {
+        // `ga` sends a high pulse on every odd press, `gb` on press 3 of every 4.
+        let input = "broadcaster -> a, b1
+%a -> ca
+&ca -> ga
+&ga -> hub
+%b1 -> b2, cb
+%b2 -> cb
+&cb -> gb
+&gb -> hub
+&hub -> rx
+";
+        assert_eq!(part_two(input), Some(3));
+    }
+
+    #[test]
+    fn combines_cycles() {
+        assert_eq!(first_common_press(&[(4, 4), (6, 6)]), Some(12));
+        assert_eq!(first_common_press(&[(3, 4), (5, 6)]), Some(11));
+        assert_eq!(first_common_press(&[(23, 4), (5, 6)]), Some(23));
+        assert_eq!(first_common_press(&[(1, 4), (2, 6)]), None);
+    }
 }
@@ -1,48 +1,33 @@
 use std::ops::RangeInclusive;
 
+use advent_of_code::helpers::{
+    parse::{Cursor, ParseResult},
+    point::{Point, Point3},
+};
 use hashbrown::{HashMap, HashSet};
-use itertools::Itertools;
 
-advent_of_code::solution!(22);
+advent_of_code::solution!(2023, 22, parse = parse_input);
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-struct Point {
-    x: u32,
-    y: u32,
-    z: u32,
-}
-
-impl TryFrom<&str> for Point {
-    type Error = String;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let (x, y, z) = value
-            .split(',')
-            .filter_map(|x| x.parse().ok())
-            .collect_tuple()
-            .ok_or("Could not parse string")?;
-        Ok(Point { x, y, z })
-    }
-}
+const UNIT_Z: Point3<u32> = Point([0, 0, 1]);
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
-struct Brick {
-    start: Point,
-    end: Point,
+pub struct Brick {
+    start: Point3<u32>,
+    end: Point3<u32>,
     id: usize,
 }
 
 impl Brick {
     fn x_bounds(&self) -> RangeInclusive<u32> {
-        self.start.x.min(self.end.x)..=self.start.x.max(self.end.x)
+        self.start.x().min(self.end.x())..=self.start.x().max(self.end.x())
     }
 
     fn y_bounds(&self) -> RangeInclusive<u32> {
-        self.start.y.min(self.end.y)..=self.start.y.max(self.end.y)
+        self.start.y().min(self.end.y())..=self.start.y().max(self.end.y())
     }
 
     fn z_bounds(&self) -> RangeInclusive<u32> {
-        self.start.z.min(self.end.z)..=self.start.z.max(self.end.z)
+        self.start.z().min(self.end.z())..=self.start.z().max(self.end.z())
     }
 
     fn overlaps(&self, other: &Brick) -> bool {
@@ -63,25 +48,21 @@ impl Brick {
     }
 }
 
-fn parse(input: &str) -> Vec<Brick> {
-    input
-        .lines()
-        .enumerate()
-        .filter_map(|(id, l)| {
-            let mut parts = l.split_ascii_whitespace();
-
-            let (start_s, end_s) = parts
-                .next()
-                .and_then(|p| p.split_once('~'))
-                .ok_or("could not parse point.")
-                .ok()?;
+fn parse(input: &str) -> ParseResult<Vec<Brick>> {
+    let mut id = 0;
 
-            let start = Point::try_from(start_s).ok()?;
-            let end = Point::try_from(end_s).ok()?;
+    Cursor::new(input).lines(|c| {
+        let start = Point(c.array(",", Cursor::integer)?);
+        c.tag("~")?;
+        let end = Point(c.array(",", Cursor::integer)?);
 
-            Some(Brick { id, start, end })
+        id += 1;
+        Ok(Brick {
+            id: id - 1,
+            start,
+            end,
         })
-        .collect()
+    })
 }
 
 fn simulate_falling(bricks: &mut [Brick]) -> usize {
@@ -105,8 +86,8 @@ fn simulate_falling(bricks: &mut [Brick]) -> usize {
             if !is_resting {
                 moved.insert(bricks[i].id);
                 any_moved = true;
-                bricks[i].end.z -= 1;
-                bricks[i].start.z -= 1;
+                bricks[i].end -= UNIT_Z;
+                bricks[i].start -= UNIT_Z;
             }
         }
 
@@ -136,15 +117,19 @@ fn build_graph(bricks: &[Brick]) -> HashMap<usize, Vec<usize>> {
     graph
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let mut bricks = parse(input);
+/// Parses the bricks and lets them fall into place, which both parts start with.
+pub fn parse_input(input: &str) -> ParseResult<Vec<Brick>> {
+    let mut bricks = parse(input)?;
     simulate_falling(&mut bricks);
+    Ok(bricks)
+}
 
-    let graph = build_graph(&bricks);
+pub fn part_one(bricks: &[Brick]) -> Option<usize> {
+    let graph = build_graph(bricks);
 
     Some(
         bricks
-            .into_iter()
+            .iter()
             .filter(|brick| {
                 graph
                     .values()
@@ -154,14 +139,11 @@ pub fn part_one(input: &str) -> Option<usize> {
     )
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
+pub fn part_two(bricks: &[Brick]) -> Option<usize> {
     let mut total = 0;
 
-    let mut bricks = parse(input);
-    simulate_falling(&mut bricks);
-
     for i in 0..bricks.len() {
-        let mut bricks_i = bricks.clone();
+        let mut bricks_i = bricks.to_vec();
         bricks_i.remove(i);
         total += simulate_falling(&mut bricks_i);
     }
@@ -175,13 +157,13 @@ mod tests {
 
     #[test]
     fn part_one_example() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(5));
+        let bricks = parse_input(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(part_one(&bricks.unwrap()), Some(5));
     }
 
     #[test]
     fn part_two_example() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
+        let bricks = parse_input(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(part_two(&bricks.unwrap()), Some(7));
     }
 }
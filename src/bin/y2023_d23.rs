@@ -0,0 +1,56 @@
+use advent_of_code::helpers::{
+    matrix::{Direction, Matrix, CARDINALS},
+    maze::JunctionGraph,
+    point::Point2,
+    search::cells,
+};
+
+advent_of_code::solution!(2023, 23);
+
+fn get_directions(c: char, handle_slopes: bool) -> &'static [Direction] {
+    match (handle_slopes, c) {
+        (true, '^') => &[Direction::N],
+        (true, '>') => &[Direction::E],
+        (true, 'v') => &[Direction::S],
+        (true, '<') => &[Direction::W],
+        _ => &CARDINALS,
+    }
+}
+
+fn solve(matrix: &Matrix, handle_slopes: bool) -> Option<usize> {
+    let start = Point2::new(1, 0);
+    let goal = Point2::new(matrix.cols - 2, matrix.rows - 1);
+
+    let graph = JunctionGraph::new(matrix, &[start, goal], |cell| {
+        cells(matrix, cell, get_directions(cell.val, handle_slopes)).filter(|c| c.val != '#')
+    });
+
+    graph.longest_path(0, 1)
+}
+
+pub fn part_one(input: &str) -> Option<usize> {
+    let matrix: Matrix<char> = Matrix::from(input);
+    solve(&matrix, true)
+}
+
+pub fn part_two(input: &str) -> Option<usize> {
+    let matrix: Matrix<char> = Matrix::from(input);
+    solve(&matrix, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_example() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(94));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(154));
+    }
+}
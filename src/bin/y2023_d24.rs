@@ -0,0 +1,166 @@
+use advent_of_code::helpers::{
+    linalg::{cross, gaussian_elimination, intersect_lines, Rational},
+    math::greatest_common_divisor,
+    parse::{Cursor, ParseError, ParseResult},
+    point::{Point, Point3},
+};
+use itertools::Itertools;
+use std::fmt::Display;
+
+advent_of_code::solution!(2023, 24);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    /// The input parsed, but has no answer.
+    Unsolvable(&'static str),
+}
+
+impl std::error::Error for Error {}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse(err) => write!(f, "{err}"),
+            Error::Unsolvable(reason) => f.write_str(reason),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct HailStone {
+    position: Point3,
+    velocity: Point3,
+}
+
+impl HailStone {
+    /// Finds where the paths of two hailstones cross in the xy-plane, ignoring the past.
+    fn intersect(&self, other: &HailStone) -> Option<(Rational, Rational)> {
+        let xy = |p: Point3| Point([p.x(), p.y()]);
+
+        let (t, s) = intersect_lines(
+            xy(self.position),
+            xy(self.velocity),
+            xy(other.position),
+            xy(other.velocity),
+        )?;
+
+        if t <= Rational::ZERO || s <= Rational::ZERO {
+            return None;
+        }
+
+        let at = |i: usize| Rational::from(self.position[i]) + t * Rational::from(self.velocity[i]);
+        Some((at(0), at(1)))
+    }
+
+    /// The position and velocity of this hailstone in the frame of reference of `other`.
+    fn relative_to(&self, other: &HailStone) -> (Point3<i128>, Point3<i128>) {
+        let wide = |p: Point3| Point(p.0.map(i128::from));
+        (
+            wide(self.position) - wide(other.position),
+            wide(self.velocity) - wide(other.velocity),
+        )
+    }
+}
+
+fn parse(input: &str) -> ParseResult<Vec<HailStone>> {
+    Cursor::new(input).lines(|c| {
+        let position = Point(c.array(",", Cursor::integer)?);
+        c.delimiter("@")?;
+        let velocity = Point(c.array(",", Cursor::integer)?);
+        Ok(HailStone { position, velocity })
+    })
+}
+
+fn solve(input: &str, min: i64, max: i64) -> ParseResult<usize> {
+    let (min, max) = (Rational::from(min), Rational::from(max));
+
+    Ok(parse(input)?
+        .into_iter()
+        .combinations(2)
+        .filter(|vals| {
+            let point = vals[0].intersect(&vals[1]);
+            point.is_some_and(|(x, y)| x >= min && x <= max && y >= min && y <= max)
+        })
+        .count())
+}
+
+pub fn part_one(input: &str) -> ParseResult<usize> {
+    solve(input, 200000000000000, 400000000000000)
+}
+
+pub fn part_two(input: &str) -> Result<i128, Error> {
+    let stones = parse(input)?;
+    throw_rock(&stones).ok_or(Error::Unsolvable("no single rock can hit every hailstone"))
+}
+
+/// Finds the position of a rock that hits every hailstone and returns the sum of its coordinates.
+fn throw_rock(stones: &[HailStone]) -> Option<i128> {
+    let (first, rest) = stones.split_first()?;
+    let [a, b] = [rest.first()?, rest.get(1)?].map(|stone| stone.relative_to(first));
+
+    // seen from the first hailstone, the rock passes through the origin. as it crosses the path of
+    // every other hailstone, it travels in the plane of that path and the origin. two of these
+    // planes meet in the line of the rock.
+    let direction = cross(cross(a.0, a.1), cross(b.0, b.1));
+    let divisor = direction
+        .0
+        .into_iter()
+        .try_fold(0, greatest_common_divisor)?;
+    if divisor == 0 {
+        return None;
+    }
+    let direction = Point(direction.0.map(|d| d / divisor));
+
+    // the rock starts at `m * direction` and moves by `k * direction` in each step. hitting a
+    // hailstone `p + t * v` means that `(m * direction - p) x (k * direction - v) = 0`, which
+    // leaves the linear equations `m * (direction x v) + k * (p x direction) = p x v`.
+    let rows = [a, b]
+        .into_iter()
+        .flat_map(|(p, v)| {
+            let (dv, pd, pv) = (cross(direction, v), cross(p, direction), cross(p, v));
+            (0..3).map(move |i| vec![dv[i].into(), pd[i].into(), pv[i].into()])
+        })
+        .collect();
+
+    let m = gaussian_elimination(rows)?[0].to_integer()?;
+
+    Some(
+        (0..3)
+            .map(|i| m * direction[i] + i128::from(first.position[i]))
+            .sum(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_example() {
+        let result = solve(
+            &advent_of_code::template::read_file("examples", PUZZLE),
+            7,
+            27,
+        );
+        assert_eq!(result, Ok(2));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Ok(47));
+    }
+
+    #[test]
+    fn part_two_too_few_hailstones() {
+        let result = part_two("19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1, -2\n");
+        assert!(matches!(result, Err(Error::Unsolvable(_))));
+    }
+}
@@ -0,0 +1,162 @@
+//! Cycle detection for simulations that eventually repeat a state.
+//!
+//! The detectors advance a state in place with `step` and return `(mu, lambda)`, where `mu` is
+//! the number of steps before the cycle is entered and `lambda` is its length. They never return
+//! if the states do not repeat.
+
+use hashbrown::HashMap;
+use std::hash::Hash;
+
+/// Floyd's tortoise and hare, which only keeps two states in memory.
+pub fn floyd<S: Clone + Eq>(start: S, mut step: impl FnMut(&mut S)) -> (usize, usize) {
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+
+    // the hare moves twice as fast and meets the tortoise somewhere in the cycle.
+    loop {
+        step(&mut tortoise);
+        step(&mut hare);
+        step(&mut hare);
+        if tortoise == hare {
+            break;
+        }
+    }
+
+    // the distance from the start to the cycle equals the distance from the meeting point.
+    let mut mu = 0;
+    tortoise = start;
+    while tortoise != hare {
+        step(&mut tortoise);
+        step(&mut hare);
+        mu += 1;
+    }
+
+    let mut lambda = 1;
+    step(&mut hare);
+    while tortoise != hare {
+        step(&mut hare);
+        lambda += 1;
+    }
+
+    (mu, lambda)
+}
+
+/// Brent's algorithm, which keeps two states in memory like [`floyd`] but needs fewer steps.
+pub fn brent<S: Clone + Eq>(start: S, mut step: impl FnMut(&mut S)) -> (usize, usize) {
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    step(&mut hare);
+
+    // the tortoise teleports to the hare whenever the hare has taken a power of two steps.
+    while tortoise != hare {
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        step(&mut hare);
+        lambda += 1;
+    }
+
+    let mut mu = 0;
+    tortoise = start.clone();
+    hare = start;
+    for _ in 0..lambda {
+        step(&mut hare);
+    }
+    while tortoise != hare {
+        step(&mut tortoise);
+        step(&mut hare);
+        mu += 1;
+    }
+
+    (mu, lambda)
+}
+
+/// Remembers the step every state was first seen at, which finds the cycle after `mu + lambda`
+/// steps. States are identified by `key`, which may be the state itself, a cheaper fingerprint or
+/// only the part of the state that is relevant.
+pub fn find_cycle<S, K: Hash + Eq>(
+    mut state: S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+) -> (usize, usize) {
+    let mut seen = HashMap::new();
+    let mut i = 0;
+
+    loop {
+        if let Some(mu) = seen.insert(key(&state), i) {
+            return (mu, i - mu);
+        }
+        step(&mut state);
+        i += 1;
+    }
+}
+
+/// The state after `n` steps. Once a state repeats, whole cycles are skipped.
+pub fn fast_forward<S, K: Hash + Eq>(
+    mut state: S,
+    n: usize,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+) -> S {
+    let mut seen = HashMap::new();
+    let mut i = 0;
+
+    while i < n {
+        if let Some(mu) = seen.insert(key(&state), i) {
+            for _ in 0..(n - i) % (i - mu) {
+                step(&mut state);
+            }
+            return state;
+        }
+        step(&mut state);
+        i += 1;
+    }
+
+    state
+}
+
+/// The first step with the same state as step `n`, given the `(mu, lambda)` of a cycle.
+pub fn equivalent_step(n: usize, (mu, lambda): (usize, usize)) -> usize {
+    if n < mu {
+        n
+    } else {
+        mu + (n - mu) % lambda
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{brent, equivalent_step, fast_forward, find_cycle, floyd};
+
+    // 0, 1, 2, 3, 4, 5, 6, 7, 3, 4, ...
+    fn step(n: &mut u32) {
+        *n = if *n < 3 { *n + 1 } else { 3 + (*n - 2) % 5 };
+    }
+
+    #[test]
+    fn detects_cycles() {
+        assert_eq!(floyd(0, step), (3, 5));
+        assert_eq!(brent(0, step), (3, 5));
+        assert_eq!(find_cycle(0, step, |n| *n), (3, 5));
+        assert_eq!(floyd(5, step), (0, 5));
+        assert_eq!(brent(5, step), (0, 5));
+    }
+
+    #[test]
+    fn detects_cycles_by_key() {
+        // the counter never repeats, but its parity does.
+        assert_eq!(find_cycle(0u64, |c| *c += 1, |c| *c % 2), (0, 2));
+    }
+
+    #[test]
+    fn fast_forwards() {
+        assert_eq!(fast_forward(0, 2, step, |n| *n), 2);
+        assert_eq!(fast_forward(0, 1_000_000_000, step, |n| *n), 5);
+        assert_eq!(equivalent_step(1_000_000_000, (3, 5)), 5);
+        assert_eq!(equivalent_step(2, (3, 5)), 2);
+    }
+}
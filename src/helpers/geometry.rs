@@ -0,0 +1,170 @@
+//! Measurements of simple polygons on the integer lattice, given as their vertices in order.
+
+use super::{math::greatest_common_divisor, matrix::Direction, point::Point2};
+
+fn edges(vertices: &[Point2]) -> impl Iterator<Item = (&Point2, &Point2)> {
+    vertices.iter().zip(vertices.iter().cycle().skip(1))
+}
+
+/// Builds a polygon by walking `moves` from the origin. The final move is expected to lead back to
+/// the origin, which is not repeated in the result.
+pub fn polygon_from_moves(moves: impl IntoIterator<Item = (Direction, i64)>) -> Vec<Point2> {
+    let mut current = Point2::origin();
+    let mut vertices = vec![];
+
+    for (dir, len) in moves {
+        vertices.push(current);
+        current += Point2::from(dir) * len;
+    }
+
+    vertices
+}
+
+/// Twice the area of a polygon, using the shoelace formula. Positive if the vertices are in
+/// clockwise order on the y-down grid.
+pub fn signed_double_area(vertices: &[Point2]) -> i64 {
+    edges(vertices)
+        .map(|(a, b)| a.x() * b.y() - a.y() * b.x())
+        .sum()
+}
+
+/// The area of a polygon, rounded down for polygons with non-integer area.
+pub fn area(vertices: &[Point2]) -> i64 {
+    signed_double_area(vertices).abs() / 2
+}
+
+/// The number of lattice points on the boundary of a polygon. For polygons with axis-aligned
+/// edges, this is the length of the boundary.
+pub fn perimeter(vertices: &[Point2]) -> i64 {
+    edges(vertices)
+        .map(|(a, b)| {
+            let d = *b - *a;
+            greatest_common_divisor(d.x(), d.y()).expect("edge of the polygon is too long")
+        })
+        .sum()
+}
+
+/// The number of lattice points strictly inside a polygon, using Pick's theorem.
+pub fn interior_points(vertices: &[Point2]) -> i64 {
+    (signed_double_area(vertices).abs() - perimeter(vertices) + 2) / 2
+}
+
+fn is_on_edge(point: &Point2, a: &Point2, b: &Point2) -> bool {
+    let (d, p) = (*b - *a, *point - *a);
+    d.x() * p.y() == d.y() * p.x()
+        && point.x() >= a.x().min(b.x())
+        && point.x() <= a.x().max(b.x())
+        && point.y() >= a.y().min(b.y())
+        && point.y() <= a.y().max(b.y())
+}
+
+pub fn is_on_boundary(vertices: &[Point2], point: &Point2) -> bool {
+    edges(vertices).any(|(a, b)| is_on_edge(point, a, b))
+}
+
+/// The number of times a polygon winds around `point`. Zero if the point is outside.
+/// Points on the boundary have no meaningful winding number, check [`is_on_boundary`] first.
+pub fn winding_number(vertices: &[Point2], point: &Point2) -> i64 {
+    edges(vertices)
+        .map(|(a, b)| {
+            // which side of the edge the point is on.
+            let side =
+                (b.x() - a.x()) * (point.y() - a.y()) - (point.x() - a.x()) * (b.y() - a.y());
+
+            if a.y() <= point.y() && b.y() > point.y() && side > 0 {
+                1
+            } else if a.y() > point.y() && b.y() <= point.y() && side < 0 {
+                -1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+/// Whether `point` lies strictly inside a polygon.
+pub fn contains(vertices: &[Point2], point: &Point2) -> bool {
+    !is_on_boundary(vertices, point) && winding_number(vertices, point) != 0
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        area, contains, interior_points, is_on_boundary, perimeter, polygon_from_moves,
+        winding_number,
+    };
+    use crate::helpers::{matrix::Direction, point::Point2};
+
+    /// The loop of the third example of 2023 day 10.
+    fn pipe_loop() -> Vec<Point2> {
+        [
+            (1, 1),
+            (9, 1),
+            (9, 7),
+            (6, 7),
+            (6, 5),
+            (8, 5),
+            (8, 2),
+            (2, 2),
+            (2, 5),
+            (4, 5),
+            (4, 7),
+            (1, 7),
+        ]
+        .map(|(x, y)| Point2::new(x, y))
+        .to_vec()
+    }
+
+    #[test]
+    fn measures_dig_plan() {
+        // the example of 2023 day 18.
+        let moves = [
+            (Direction::E, 6),
+            (Direction::S, 5),
+            (Direction::W, 2),
+            (Direction::S, 2),
+            (Direction::E, 2),
+            (Direction::S, 2),
+            (Direction::W, 5),
+            (Direction::N, 2),
+            (Direction::W, 1),
+            (Direction::N, 2),
+            (Direction::E, 2),
+            (Direction::N, 3),
+            (Direction::W, 2),
+            (Direction::N, 2),
+        ];
+
+        let polygon = polygon_from_moves(moves);
+        assert_eq!(polygon.len(), 14);
+        assert_eq!(polygon[1], Point2::new(6, 0));
+        assert_eq!(area(&polygon), 42);
+        assert_eq!(perimeter(&polygon), 38);
+        assert_eq!(interior_points(&polygon) + perimeter(&polygon), 62);
+    }
+
+    #[test]
+    fn counts_enclosed_tiles() {
+        let polygon = pipe_loop();
+        assert_eq!(interior_points(&polygon), 4);
+
+        let enclosed = (0..9)
+            .flat_map(|y| (0..11).map(move |x| Point2::new(x, y)))
+            .filter(|p| contains(&polygon, p))
+            .count();
+        assert_eq!(enclosed, 4);
+    }
+
+    #[test]
+    fn finds_points_in_polygon() {
+        let polygon = pipe_loop();
+        assert!(contains(&polygon, &Point2::new(2, 6)));
+        assert_eq!(winding_number(&polygon, &Point2::new(2, 6)).abs(), 1);
+        assert!(!contains(&polygon, &Point2::new(5, 6)));
+        assert!(!contains(&polygon, &Point2::new(4, 3)));
+        assert!(!contains(&polygon, &Point2::new(0, 0)));
+
+        assert!(is_on_boundary(&polygon, &Point2::new(5, 1)));
+        assert!(!contains(&polygon, &Point2::new(5, 1)));
+    }
+}
@@ -0,0 +1,237 @@
+//! Sets of integers stored as ranges, for puzzles that map or filter large ranges of values.
+
+use std::ops::{Range, RangeInclusive};
+
+use super::math::Integer;
+
+/// A set of integers, stored as sorted half-open ranges that neither overlap nor touch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct RangeSet<T = i64> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Integer> RangeSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: vec![] }
+    }
+
+    /// Sorts `ranges` and merges the ones that overlap or touch.
+    fn normalize(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.retain(|r| r.start < r.end);
+        ranges.sort_unstable_by_key(|r| r.start);
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+
+        Self { ranges: merged }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Range<T>> {
+        self.ranges.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::ZERO, |acc, r| acc + (r.end - r.start))
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.ranges.iter().any(|r| r.contains(value))
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|r| r.end - T::ONE)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        *self = Self::normalize(ranges);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalize(self.iter().chain(other.iter()).cloned().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (a, b) = (&self.ranges, &other.ranges);
+        let (mut i, mut j) = (0, 0);
+        let mut ranges = vec![];
+
+        while i < a.len() && j < b.len() {
+            let start = a[i].start.max(b[j].start);
+            let end = a[i].end.min(b[j].end);
+
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            // the range that ends first can not overlap anything else.
+            if a[i].end < b[j].end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// The integers of `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let others = &other.ranges;
+        let mut j = 0;
+        let mut ranges = vec![];
+
+        for range in &self.ranges {
+            let mut start = range.start;
+
+            while j < others.len() && others[j].end <= start {
+                j += 1;
+            }
+
+            // ranges of `other` may reach into the next range, so `j` is not advanced here.
+            for cut in others[j..].iter().take_while(|cut| cut.start < range.end) {
+                if cut.start > start {
+                    ranges.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Splits the set into the integers below `at` and the ones at or above it.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = vec![];
+        let mut above = vec![];
+
+        for range in &self.ranges {
+            if range.end <= at {
+                below.push(range.clone());
+            } else if range.start >= at {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..at);
+                above.push(at..range.end);
+            }
+        }
+
+        (Self { ranges: below }, Self { ranges: above })
+    }
+
+    /// Moves the integers that are in one of the `source` ranges of `mappings` so that `source.start`
+    /// ends up at `destination`. The first matching mapping applies, integers that are not in any
+    /// source are kept as they are.
+    pub fn map_piecewise(&self, mappings: &[(Range<T>, T)]) -> Self {
+        let mut unmapped = self.clone();
+        let mut mapped = vec![];
+
+        for (source, destination) in mappings {
+            let moved = unmapped.intersection(&Self::from(source.clone()));
+
+            mapped.extend(moved.iter().map(|r| {
+                r.start - source.start + *destination..r.end - source.start + *destination
+            }));
+
+            unmapped = unmapped.difference(&moved);
+        }
+
+        mapped.extend(unmapped.ranges);
+        Self::normalize(mapped)
+    }
+}
+
+impl<T: Integer> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::normalize(vec![range])
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for RangeSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Self::from(*range.start()..*range.end() + T::ONE)
+    }
+}
+
+impl<T: Integer> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        Self::normalize(iter.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::RangeSet;
+
+    fn set(ranges: &[(i64, i64)]) -> RangeSet {
+        ranges.iter().map(|(start, end)| *start..*end).collect()
+    }
+
+    #[test]
+    fn normalizes_ranges() {
+        let ranges = set(&[(5, 8), (0, 2), (1, 3), (3, 4), (10, 10)]);
+        assert_eq!(ranges.ranges(), &[0..4, 5..8]);
+        assert_eq!(ranges.len(), 7);
+        assert_eq!((ranges.min(), ranges.max()), (Some(0), Some(7)));
+        assert!(ranges.contains(&3) && !ranges.contains(&4));
+        assert_eq!(RangeSet::from(1..=4000).len(), 4000);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 12), (14, 20)]);
+        assert_eq!(a.union(&b), set(&[(0, 20)]));
+        assert_eq!(a.intersection(&b), set(&[(3, 5), (10, 12), (14, 15)]));
+        assert_eq!(a.difference(&b), set(&[(0, 3), (12, 14)]));
+        assert_eq!(b.difference(&a), set(&[(5, 10), (15, 20)]));
+        assert_eq!(a.difference(&a), RangeSet::new());
+    }
+
+    #[test]
+    fn splits_sets() {
+        let (below, above) = set(&[(0, 5), (10, 15)]).split_at(12);
+        assert_eq!(below, set(&[(0, 5), (10, 12)]));
+        assert_eq!(above, set(&[(12, 15)]));
+    }
+
+    #[test]
+    fn maps_pieces() {
+        // the seed-to-soil map of the 2023 day 5 example.
+        let seeds: RangeSet<usize> = [79..93, 55..68].into_iter().collect();
+        let soil = seeds.map_piecewise(&[(98..100, 50), (50..98, 52)]);
+        assert_eq!(soil.ranges(), &[57..70, 81..95]);
+
+        let shifted = set(&[(0, 10)]).map_piecewise(&[(5..8, -5), (2..4, 20), (3..6, 30)]);
+        assert_eq!(
+            shifted,
+            set(&[(-5, -2), (0, 2), (8, 10), (20, 22), (31, 32)])
+        );
+    }
+}
@@ -0,0 +1,345 @@
+//! Exact linear algebra over rational numbers backed by `i128`.
+
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use super::{math::greatest_common_divisor, point::Point};
+
+/// A fraction in lowest terms with a positive denominator.
+///
+/// Arithmetic panics if an intermediate result does not fit into `i128`, so results are never
+/// silently wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+const OVERFLOW: &str = "rational arithmetic overflowed i128";
+
+impl Rational {
+    pub const ZERO: Rational = Rational { num: 0, den: 1 };
+    pub const ONE: Rational = Rational { num: 1, den: 1 };
+
+    /// # Panics
+    ///
+    /// If `den` is zero.
+    pub fn new(num: i128, den: i128) -> Self {
+        assert!(den != 0, "denominator of a rational must not be zero");
+
+        let g = greatest_common_divisor(num, den).expect(OVERFLOW);
+        let sign = if den < 0 { -1 } else { 1 };
+
+        Self {
+            num: sign * num / g,
+            den: sign * den / g,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.num
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.den
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    /// # Panics
+    ///
+    /// If the value is zero.
+    pub fn recip(&self) -> Self {
+        Self::new(self.den, self.num)
+    }
+
+    /// The value as an integer, if it is one.
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.den == 1).then_some(self.num)
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(n: i128) -> Self {
+        Self { num: n, den: 1 }
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Self::from(n as i128)
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let g = greatest_common_divisor(self.den, rhs.den).expect(OVERFLOW);
+        let num = self
+            .num
+            .checked_mul(rhs.den / g)
+            .zip(rhs.num.checked_mul(self.den / g))
+            .and_then(|(a, b)| a.checked_add(b))
+            .expect(OVERFLOW);
+        let den = (self.den / g).checked_mul(rhs.den).expect(OVERFLOW);
+        Self::new(num, den)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // cancel before multiplying to keep the intermediate values small.
+        let g1 = greatest_common_divisor(self.num, rhs.den)
+            .expect(OVERFLOW)
+            .max(1);
+        let g2 = greatest_common_divisor(rhs.num, self.den)
+            .expect(OVERFLOW)
+            .max(1);
+        let num = (self.num / g1).checked_mul(rhs.num / g2).expect(OVERFLOW);
+        let den = (self.den / g2).checked_mul(rhs.den / g1).expect(OVERFLOW);
+        Self::new(num, den)
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If `rhs` is zero.
+    fn div(self, rhs: Self) -> Self::Output {
+        self.mul(rhs.recip())
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (
+            self.num.checked_mul(other.den),
+            other.num.checked_mul(self.den),
+        ) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => (*self - *other).num.cmp(&0),
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.den {
+            1 => write!(f, "{}", self.num),
+            den => write!(f, "{}/{}", self.num, den),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Solves a system of linear equations, given as the rows of its augmented matrix `[A | b]`.
+/// Returns `None` unless the system has exactly one solution. There may be more equations than
+/// unknowns, as long as they are consistent.
+pub fn gaussian_elimination(mut rows: Vec<Vec<Rational>>) -> Option<Vec<Rational>> {
+    let unknowns = rows.first()?.len().checked_sub(1)?;
+
+    for col in 0..unknowns {
+        let pivot = (col..rows.len()).find(|&row| !rows[row][col].is_zero())?;
+        rows.swap(col, pivot);
+
+        let factor = rows[col][col];
+        rows[col].iter_mut().for_each(|v| *v = *v / factor);
+        let pivot_row = rows[col].clone();
+
+        for (row, values) in rows.iter_mut().enumerate() {
+            let factor = values[col];
+            if row == col || factor.is_zero() {
+                continue;
+            }
+
+            for (v, pivot) in values.iter_mut().zip(&pivot_row).skip(col) {
+                *v = *v - factor * *pivot;
+            }
+        }
+    }
+
+    // left over equations have been reduced to `0 = b`, which only holds for `b = 0`.
+    if rows[unknowns..].iter().any(|row| !row[unknowns].is_zero()) {
+        return None;
+    }
+
+    Some(
+        rows.into_iter()
+            .take(unknowns)
+            .map(|row| row[unknowns])
+            .collect(),
+    )
+}
+
+/// Finds `(t, s)` such that `p1 + t * d1 == p2 + s * d2`, if the lines intersect in one point.
+pub fn intersect_lines<const N: usize>(
+    p1: Point<i64, N>,
+    d1: Point<i64, N>,
+    p2: Point<i64, N>,
+    d2: Point<i64, N>,
+) -> Option<(Rational, Rational)> {
+    let [p1, d1, p2, d2] = [p1, d1, p2, d2].map(|p| p.0.map(i128::from));
+    let diff: [i128; N] = std::array::from_fn(|i| p2[i] - p1[i]);
+
+    // solve the system of two coordinates with cramer's rule, the lines are parallel if there is
+    // no pair of coordinates with a non-zero determinant.
+    let (det, t, s) = (0..N)
+        .flat_map(|i| (i + 1..N).map(move |j| (i, j)))
+        .find_map(|(i, j)| {
+            let det = d2[i] * d1[j] - d1[i] * d2[j];
+            (det != 0).then(|| {
+                (
+                    det,
+                    d2[i] * diff[j] - diff[i] * d2[j],
+                    d1[i] * diff[j] - diff[i] * d1[j],
+                )
+            })
+        })?;
+
+    // the remaining coordinates have to agree as well.
+    (0..N)
+        .all(|i| diff[i] * det == t * d1[i] - s * d2[i])
+        .then(|| (Rational::new(t, det), Rational::new(s, det)))
+}
+
+pub fn cross(a: Point<i128, 3>, b: Point<i128, 3>) -> Point<i128, 3> {
+    Point([
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ])
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{cross, gaussian_elimination, intersect_lines, Rational};
+    use crate::helpers::point::{Point, Point2, Point3};
+
+    fn r(num: i128, den: i128) -> Rational {
+        Rational::new(num, den)
+    }
+
+    #[test]
+    fn computes_with_fractions() {
+        assert_eq!(r(2, -4), r(-1, 2));
+        assert_eq!(r(1, 3) + r(1, 6), r(1, 2));
+        assert_eq!(r(1, 3) - r(1, 2), r(-1, 6));
+        assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+        assert_eq!(r(2, 3) / r(-4, 3), r(-1, 2));
+        assert!(r(1, 3) < r(1, 2));
+        assert_eq!(r(6, 3).to_integer(), Some(2));
+        assert_eq!(r(1, 3).to_string(), "1/3");
+
+        let big = Rational::from(i64::MAX as i128 * 1_000);
+        assert_eq!((big * r(1, 1_000)).to_integer(), Some(i64::MAX as i128));
+    }
+
+    #[test]
+    fn solves_linear_systems() {
+        // x + y + z = 6, 2y + 5z = -4, 2x + 5y - z = 27
+        let rows = vec![vec![1i128, 1, 1, 6], vec![0, 2, 5, -4], vec![2, 5, -1, 27]]
+            .into_iter()
+            .map(|row| row.into_iter().map(Rational::from).collect())
+            .collect();
+        assert_eq!(
+            gaussian_elimination(rows),
+            Some(vec![5i128.into(), 3i128.into(), (-2i128).into()])
+        );
+
+        let singular = vec![
+            vec![r(1, 1), r(2, 1), r(3, 1)],
+            vec![r(2, 1), r(4, 1), r(6, 1)],
+        ];
+        assert_eq!(gaussian_elimination(singular), None);
+
+        let inconsistent = vec![vec![r(1, 1), r(1, 1)], vec![r(2, 1), r(3, 1)]];
+        assert_eq!(gaussian_elimination(inconsistent), None);
+    }
+
+    #[test]
+    fn intersects_lines() {
+        // hailstones A and B of the 2023 day 24 example cross at (14.333, 15.333).
+        let (t, s) = intersect_lines(
+            Point2::new(19, 13),
+            Point2::new(-2, 1),
+            Point2::new(18, 19),
+            Point2::new(-1, -1),
+        )
+        .unwrap();
+        assert_eq!(Rational::from(19i64) + t * Rational::from(-2i64), r(43, 3));
+        assert!(t > Rational::ZERO && s > Rational::ZERO);
+
+        assert_eq!(
+            intersect_lines(
+                Point2::new(0, 0),
+                Point2::new(1, 1),
+                Point2::new(1, 0),
+                Point2::new(2, 2)
+            ),
+            None
+        );
+
+        assert_eq!(
+            intersect_lines(
+                Point3::new(0, 0, 0),
+                Point3::new(1, 1, 1),
+                Point3::new(2, 0, 2),
+                Point3::new(0, 1, 0)
+            ),
+            Some((r(2, 1), r(2, 1)))
+        );
+        assert_eq!(
+            intersect_lines(
+                Point3::new(0, 0, 0),
+                Point3::new(1, 0, 0),
+                Point3::new(0, 1, 1),
+                Point3::new(0, 0, 1)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn multiplies_vectors() {
+        let a = Point([1i128, 2, 3]);
+        let b = Point([4i128, 5, 6]);
+        assert_eq!(cross(a, b), Point([-3, 6, -3]));
+        assert_eq!(cross(a, a), Point([0, 0, 0]));
+    }
+}
@@ -1,15 +1,331 @@
-pub fn least_common_multiple(nums: &[usize]) -> usize {
-    if nums.len() == 1 {
-        return nums[0];
+//! Number theory for the primitive integer types.
+
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+}
+
+/// Integers that can be negative, which the extended euclidean algorithm relies on.
+pub trait Signed: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+
+                fn checked_rem(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_rem(self, rhs)
+                }
+
+                fn checked_neg(self) -> Option<Self> {
+                    <$t>::checked_neg(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Signed for i8 {}
+impl Signed for i16 {}
+impl Signed for i32 {}
+impl Signed for i64 {}
+impl Signed for i128 {}
+impl Signed for isize {}
+
+/// The absolute value of `n`, or `None` if it does not fit into `T`, which is the case for `T::MIN`.
+fn checked_abs<T: Integer>(n: T) -> Option<T> {
+    if n < T::ZERO {
+        n.checked_neg()
+    } else {
+        Some(n)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The greatest common divisor of `a` and `b`, which is never negative.
+/// Returns `None` if it does not fit into `T`, e.g. for `gcd(i32::MIN, 0) = 2^31`.
+pub fn greatest_common_divisor<T: Integer>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a, b);
+
+    while b != T::ZERO {
+        // NOTE: `T::MIN % -1` overflows, but the remainder is zero.
+        (a, b) = (b, a.checked_rem(b).unwrap_or(T::ZERO));
+    }
+
+    checked_abs(a)
+}
+
+/// The least common multiple of all `nums`, or `None` if it does not fit into `T` or `nums` is empty.
+pub fn least_common_multiple<T: Integer>(nums: &[T]) -> Option<T> {
+    let (first, rest) = nums.split_first()?;
+
+    rest.iter().try_fold(checked_abs(*first)?, |acc, &n| {
+        if acc == T::ZERO || n == T::ZERO {
+            return Some(T::ZERO);
+        }
+        (acc / greatest_common_divisor(acc, n)?).checked_mul(checked_abs(n)?)
+    })
+}
+
+/// Finds `(g, x, y)` such that `a * x + b * y == g`, where `g` is the greatest common divisor.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Finds `x` in `0..m` such that `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+/// Returns `None` otherwise, or if `m` is not positive.
+pub fn mod_inverse<T: Signed>(a: T, m: T) -> Option<T> {
+    if m <= T::ZERO {
+        return None;
     }
-    let a = nums[0];
-    let b = least_common_multiple(&nums[1..]);
-    a * b / greatest_common_divisor(a, b)
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == T::ONE).then(|| x.rem_euclid(m))
+}
+
+/// Solves the system `x ≡ residue (mod modulus)` for every pair of `congruences`.
+/// Returns the smallest non-negative solution and the modulus it repeats with, or `None` if the
+/// congruences contradict each other or a modulus is not positive. The moduli do not need to be
+/// coprime.
+pub fn chinese_remainder<T: Signed>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences
+        .iter()
+        .try_fold((T::ZERO, T::ONE), |(x, m), &(residue, modulus)| {
+            if modulus <= T::ZERO {
+                return None;
+            }
+
+            let (g, p, _) = extended_gcd(m, modulus);
+            let diff = residue - x;
+
+            if diff % g != T::ZERO {
+                return None;
+            }
+
+            let lcm = (m / g).checked_mul(modulus)?;
+            let step = mod_mul(
+                (diff / g).rem_euclid(modulus / g),
+                p.rem_euclid(modulus / g),
+                modulus / g,
+            );
+            let x = mod_add(x, mod_mul(m, step, lcm), lcm);
+
+            Some((x, lcm))
+        })
 }
 
-fn greatest_common_divisor(a: usize, b: usize) -> usize {
-    if b == 0 {
-        return a;
+/// Computes `base.pow(exp) % m` without overflowing, for any `m` that fits into `T`.
+/// Returns `None` if `exp` is negative or `m` is not positive.
+pub fn mod_pow<T: Integer>(base: T, exp: T, m: T) -> Option<T> {
+    if exp < T::ZERO || m <= T::ZERO {
+        return None;
+    }
+
+    let two = T::ONE + T::ONE;
+    let mut result = T::ONE % m;
+    let mut base = base.rem_euclid(m);
+    let mut exp = exp;
+
+    while exp > T::ZERO {
+        if exp % two == T::ONE {
+            result = mod_mul(result, base, m);
+        }
+        base = mod_mul(base, base, m);
+        exp = exp / two;
+    }
+
+    Some(result)
+}
+
+/// Computes `a * b % m` for `a` and `b` in `0..m` without overflowing.
+fn mod_mul<T: Integer>(a: T, b: T, m: T) -> T {
+    if let Some(product) = a.checked_mul(b) {
+        return product % m;
+    }
+
+    let two = T::ONE + T::ONE;
+    let (mut a, mut b) = (a, b);
+    let mut result = T::ZERO;
+
+    while b > T::ZERO {
+        if b % two == T::ONE {
+            result = mod_add(result, a, m);
+        }
+        a = mod_add(a, a, m);
+        b = b / two;
+    }
+
+    result
+}
+
+fn mod_add<T: Integer>(a: T, b: T, m: T) -> T {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// The largest integer whose square is at most `n`, or `None` if `n` is negative.
+pub fn isqrt<T: Integer>(n: T) -> Option<T> {
+    if n < T::ZERO {
+        return None;
+    }
+
+    let two = T::ONE + T::ONE;
+    if n < two {
+        return Some(n);
+    }
+
+    // newton's method, starting above the root so that the estimates decrease monotonically.
+    let mut x = n / two + T::ONE;
+    let mut y = (x + n / x) / two;
+
+    while y < x {
+        x = y;
+        y = (x + n / x) / two;
+    }
+
+    Some(x)
+}
+
+trait RemEuclid {
+    fn rem_euclid(self, m: Self) -> Self;
+}
+
+impl<T: Integer> RemEuclid for T {
+    fn rem_euclid(self, m: Self) -> Self {
+        let r = self % m;
+        match (r < T::ZERO, m < T::ZERO) {
+            (true, true) => r - m,
+            (true, false) => r + m,
+            (false, _) => r,
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        chinese_remainder, extended_gcd, greatest_common_divisor, isqrt, least_common_multiple,
+        mod_inverse, mod_pow,
+    };
+
+    #[test]
+    fn finds_divisors_and_multiples() {
+        assert_eq!(greatest_common_divisor(12u32, 18), Some(6));
+        assert_eq!(greatest_common_divisor(-12i64, 18), Some(6));
+        assert_eq!(greatest_common_divisor(0, 5), Some(5));
+        assert_eq!(greatest_common_divisor(i32::MIN, 6), Some(2));
+        assert_eq!(greatest_common_divisor(i32::MIN, -1), Some(1));
+        assert_eq!(greatest_common_divisor(i32::MIN, 0), None);
+        assert_eq!(greatest_common_divisor(i32::MIN, i32::MIN), None);
+
+        assert_eq!(least_common_multiple(&[4usize, 6, 10]), Some(60));
+        assert_eq!(least_common_multiple::<usize>(&[]), None);
+        assert_eq!(least_common_multiple(&[u8::MAX, 2]), None);
+        assert_eq!(least_common_multiple(&[i8::MIN, 2]), None);
+        assert_eq!(least_common_multiple(&[-4i8, 6]), Some(12));
+        assert_eq!(
+            least_common_multiple(&[3917u64, 3923, 4001, 4091]),
+            Some(251_518_486_229_581)
+        );
+    }
+
+    #[test]
+    fn solves_extended_euclid() {
+        let (g, x, y) = extended_gcd(240i64, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        assert_eq!(mod_inverse(3i64, 11), Some(4));
+        assert_eq!(mod_inverse(-3i64, 11), Some(7));
+        assert_eq!(mod_inverse(4i64, 8), None);
+        assert_eq!(mod_inverse(3i64, 0), None);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(
+            chinese_remainder(&[(2i64, 3), (3, 5), (2, 7)]),
+            Some((23, 105))
+        );
+        // cycles with offsets and shared factors.
+        assert_eq!(chinese_remainder(&[(3i64, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(chinese_remainder(&[(1i64, 4), (2, 6)]), None);
+        assert_eq!(chinese_remainder(&[(1i64, 4), (2, 0)]), None);
+        // the solution is close to `i64::MAX`.
+        assert_eq!(
+            chinese_remainder(&[(-1i64, 3_037_000_499), (-1, 3_037_000_498)]),
+            Some((
+                3_037_000_499 * 3_037_000_498 - 1,
+                3_037_000_499 * 3_037_000_498
+            ))
+        );
+        assert_eq!(
+            chinese_remainder(&[(0i128, 1_000_000_007), (1, 998_244_353)]),
+            Some((
+                (1_000_000_007 * mod_inverse(1_000_000_007i128, 998_244_353).unwrap())
+                    % (1_000_000_007 * 998_244_353),
+                1_000_000_007 * 998_244_353
+            ))
+        );
+    }
+
+    #[test]
+    fn computes_powers_and_roots() {
+        assert_eq!(mod_pow(4u32, 13, 497), Some(445));
+        assert_eq!(mod_pow(2u64, 64, u64::MAX), Some(1));
+        assert_eq!(mod_pow(7i64, 0, 1), Some(0));
+        assert_eq!(mod_pow(2i64, -1, 5), None);
+        assert_eq!(mod_pow(2u32, 3, 0), None);
+
+        assert_eq!(isqrt(0u32), Some(0));
+        assert_eq!(isqrt(15u32), Some(3));
+        assert_eq!(isqrt(16u32), Some(4));
+        assert_eq!(isqrt(u64::MAX), Some(u32::MAX as u64));
+        assert_eq!(isqrt(-1i32), None);
     }
-    greatest_common_divisor(b, a % b)
 }
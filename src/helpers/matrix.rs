@@ -1,9 +1,14 @@
+use hashbrown::HashMap;
 use itertools::Itertools;
 use std::{
+    error::Error,
     fmt::Display,
     hash::{Hash, Hasher},
+    str::FromStr,
 };
 
+use super::{point::Point2, search};
+
 /* -------------------------------------------------------------------------- */
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -31,6 +36,37 @@ impl Direction {
             Direction::SW => Direction::NE,
         }
     }
+
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::N => Direction::W,
+            Direction::E => Direction::N,
+            Direction::S => Direction::E,
+            Direction::W => Direction::S,
+            Direction::NE => Direction::NW,
+            Direction::SE => Direction::NE,
+            Direction::SW => Direction::SE,
+            Direction::NW => Direction::SW,
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        self.turn_left().invert()
+    }
+
+    /// The `(row, col)` offset of a step in this direction.
+    pub fn offset(&self) -> (i64, i64) {
+        match self {
+            Direction::N => (-1, 0),
+            Direction::E => (0, 1),
+            Direction::S => (1, 0),
+            Direction::W => (0, -1),
+            Direction::NE => (-1, 1),
+            Direction::SE => (1, 1),
+            Direction::SW => (1, -1),
+            Direction::NW => (-1, -1),
+        }
+    }
 }
 
 pub static CARDINALS: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
@@ -39,16 +75,11 @@ pub static ORDINALS: [Direction; 4] = [Direction::NW, Direction::NE, Direction::
 
 /* -------------------------------------------------------------------------- */
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Point {
-    pub col: usize,
-    pub row: usize,
-}
-
-#[derive(Debug, Clone, Copy, Eq)]
+#[derive(Debug, Clone, Copy)]
 pub struct Cell<T: Copy + Display = char> {
     pub val: T,
-    pub point: Point,
+    /// The position of the cell, `x` is its column and `y` its row.
+    pub point: Point2<usize>,
 }
 
 impl<T: Copy + Display> PartialEq for Cell<T> {
@@ -57,6 +88,8 @@ impl<T: Copy + Display> PartialEq for Cell<T> {
     }
 }
 
+impl<T: Copy + Display> Eq for Cell<T> {}
+
 impl<T: Copy + Display> Hash for Cell<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.point.hash(state);
@@ -65,173 +98,217 @@ impl<T: Copy + Display> Hash for Cell<T> {
 
 impl<T: Copy + Display> Display for Cell<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "[{}:{}] {}", self.point.row, self.point.col, self.val)
+        write!(f, "[{}:{}] {}", self.point.y(), self.point.x(), self.val)
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-#[derive(Debug)]
+/// A grid stored as a single row-major `Vec`, the cell at `(row, col)` lives at `row * cols + col`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Matrix<T: Copy = char> {
-    pub cells: Vec<Vec<T>>,
+    pub cells: Vec<T>,
     pub cols: usize,
     pub rows: usize,
 }
 
 impl From<&str> for Matrix<char> {
     fn from(s: &str) -> Self {
-        let cells: Vec<Vec<char>> = s
-            .lines()
-            .filter_map(|l| {
-                if !l.is_empty() {
-                    Some(l.chars().collect())
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        Self {
-            cols: cells[0].len(),
-            rows: cells.len(),
-            cells,
-        }
+        Self::parse_with(s, Ok::<_, InvalidChar>).unwrap_or_else(|err| panic!("{err}"))
     }
 }
 
 impl From<&str> for Matrix<u32> {
     fn from(s: &str) -> Self {
-        let cells: Vec<Vec<u32>> = s
-            .lines()
-            .filter_map(|l| {
-                if !l.is_empty() {
-                    Some(l.chars().map(|x| x.to_digit(10).unwrap()).collect())
-                } else {
-                    None
-                }
-            })
-            .collect();
+        Self::parse_with(s, |c| c.to_digit(10).ok_or(InvalidChar(c)))
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+}
 
+impl<T: Copy + TryFrom<char>> FromStr for Matrix<T> {
+    type Err = ParseMatrixError<T::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, T::try_from)
+    }
+}
+
+impl<T: Copy> Matrix<T> {
+    pub fn new(rows: usize, cols: usize, val: T) -> Self {
         Self {
-            cols: cells[0].len(),
-            rows: cells.len(),
-            cells,
+            cells: vec![val; rows * cols],
+            cols,
+            rows,
         }
     }
+
+    /// Parses every non-empty line of `s` as a row, converting characters with `parse`.
+    pub fn parse_with<E>(
+        s: &str,
+        parse: impl Fn(char) -> Result<T, E>,
+    ) -> Result<Self, ParseMatrixError<E>> {
+        let mut cells = vec![];
+        let mut cols = 0;
+        let mut rows = 0;
+
+        for line in s.lines().filter(|l| !l.is_empty()) {
+            let mut found = 0;
+
+            for (col, c) in line.chars().enumerate() {
+                let val = parse(c).map_err(|err| ParseMatrixError::Cell {
+                    row: rows,
+                    col,
+                    err,
+                })?;
+                cells.push(val);
+                found += 1;
+            }
+
+            if rows == 0 {
+                cols = found;
+            } else if found != cols {
+                return Err(ParseMatrixError::Ragged {
+                    row: rows,
+                    expected: cols,
+                    found,
+                });
+            }
+
+            rows += 1;
+        }
+
+        if rows == 0 {
+            return Err(ParseMatrixError::Empty);
+        }
+
+        Ok(Self { cells, cols, rows })
+    }
+}
+
+impl<T: Copy + Display> Display for Matrix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for val in row {
+                write!(f, "{val}")?;
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<T: Copy + Display> Matrix<T> {
+    fn index(&self, row: usize, col: usize) -> Option<usize> {
+        if row < self.rows && col < self.cols {
+            Some(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
     pub fn get(&self, row: usize, col: usize) -> Option<T> {
-        self.cells.get(row).and_then(|l| l.get(col).copied())
+        self.index(row, col).map(|i| self.cells[i])
     }
 
-    pub fn get_row(&self, row: usize) -> Option<&Vec<T>> {
-        self.cells.get(row)
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.index(row, col).map(|i| &mut self.cells[i])
     }
 
-    pub fn get_row_mut(&mut self, row: usize) -> Option<&mut Vec<T>> {
-        self.cells.get_mut(row)
+    pub fn get_row(&self, row: usize) -> Option<&[T]> {
+        let start = self.index(row, 0)?;
+        Some(&self.cells[start..start + self.cols])
     }
 
-    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
-        self.cells.get_mut(row).and_then(|l| l.get_mut(col))
+    pub fn get_row_mut(&mut self, row: usize) -> Option<&mut [T]> {
+        let start = self.index(row, 0)?;
+        Some(&mut self.cells[start..start + self.cols])
+    }
+
+    pub fn get_col(
+        &self,
+        col: usize,
+    ) -> Option<impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
+        self.index(0, col)?;
+        Some(self.cells.iter().skip(col).step_by(self.cols))
+    }
+
+    pub fn get_col_mut(
+        &mut self,
+        col: usize,
+    ) -> Option<impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator> {
+        self.index(0, col)?;
+        Some(self.cells.iter_mut().skip(col).step_by(self.cols))
+    }
+
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks_exact(self.cols.max(1))
+    }
+
+    pub fn iter_rows_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = &mut [T]> + ExactSizeIterator {
+        self.cells.chunks_exact_mut(self.cols.max(1))
+    }
+
+    /// Borrows the `rows` x `cols` sub-grid whose top-left corner is at `(row, col)`.
+    pub fn view(&self, row: usize, col: usize, rows: usize, cols: usize) -> Option<View<'_, T>> {
+        if row + rows > self.rows || col + cols > self.cols {
+            return None;
+        }
+
+        Some(View {
+            matrix: self,
+            row,
+            col,
+            rows,
+            cols,
+        })
+    }
+
+    /// Treats the matrix as if it was repeated infinitely in every direction.
+    /// Returns `None` for an empty matrix, which has no cells to repeat.
+    pub fn tiled(&self) -> Option<Tiled<'_, T>> {
+        (self.rows > 0 && self.cols > 0).then_some(Tiled { matrix: self })
     }
 
     pub fn get_cell(&self, row: usize, col: usize) -> Option<Cell<T>> {
         self.get(row, col).map(|val| Cell {
-            point: Point { col, row },
+            point: Point2::new(col, row),
             val,
         })
     }
 
     pub fn items(&self) -> impl Iterator<Item = Cell<T>> + '_ {
-        (0..self.rows)
-            .cartesian_product(0..self.cols)
-            .map(|(row, col)| self.get_cell(row, col).unwrap())
+        self.cells.iter().enumerate().map(|(i, &val)| Cell {
+            point: Point2::new(i % self.cols, i / self.cols),
+            val,
+        })
     }
 
     pub fn neighbour(&self, cell: &Cell<T>, dir: &Direction) -> Option<Cell<T>> {
-        match dir {
-            Direction::NW => {
-                let row = cell.point.row.checked_sub(1)?;
-                let col = cell.point.col.checked_sub(1)?;
-                let val = self.get(row, col)?;
-                Some(Cell {
-                    point: Point { col, row },
-                    val,
-                })
-            }
-            Direction::N => {
-                let col = cell.point.col;
-                let row = cell.point.row.checked_sub(1)?;
-                let val = self.get(row, col)?;
-                Some(Cell {
-                    point: Point { col, row },
-                    val,
-                })
-            }
-            Direction::NE => {
-                let col = cell.point.col + 1;
-                let row = cell.point.row.checked_sub(1)?;
-                let val = self.get(row, col)?;
-                Some(Cell {
-                    point: Point { col, row },
-                    val,
-                })
-            }
-            Direction::W => {
-                let col = cell.point.col.checked_sub(1)?;
-                let row = cell.point.row;
-                let val = self.get(row, col)?;
-                Some(Cell {
-                    point: Point { col, row },
-                    val,
-                })
-            }
-            Direction::E => {
-                let col = cell.point.col + 1;
-                let row = cell.point.row;
-                let val = self.get(row, col)?;
-                Some(Cell {
-                    point: Point { col, row },
-                    val,
-                })
-            }
-            Direction::SW => {
-                let col = cell.point.col.checked_sub(1)?;
-                let row = cell.point.row + 1;
-                let val = self.get(row, col)?;
-                Some(Cell {
-                    point: Point { col, row },
-                    val,
-                })
-            }
-            Direction::S => {
-                let row = cell.point.row + 1;
-                let col = cell.point.col;
-                let val = self.get(row, col)?;
-                Some(Cell {
-                    point: Point { col, row },
-                    val,
-                })
-            }
-            Direction::SE => {
-                let col = cell.point.col + 1;
-                let row = cell.point.row + 1;
-                let val = self.get(row, col)?;
-                Some(Cell {
-                    point: Point { col, row },
-                    val,
-                })
-            }
-        }
+        let (col, row) = (cell.point.x(), cell.point.y());
+
+        let (row, col) = match dir {
+            Direction::NW => (row.checked_sub(1)?, col.checked_sub(1)?),
+            Direction::N => (row.checked_sub(1)?, col),
+            Direction::NE => (row.checked_sub(1)?, col + 1),
+            Direction::W => (row, col.checked_sub(1)?),
+            Direction::E => (row, col + 1),
+            Direction::SW => (row + 1, col.checked_sub(1)?),
+            Direction::S => (row + 1, col),
+            Direction::SE => (row + 1, col + 1),
+        };
+
+        self.get_cell(row, col)
     }
 
     pub fn neighbours<'a, 'b: 'a>(
         &'a self,
         start: &'b Cell<T>,
         directions: &'b [Direction],
-    ) -> impl Iterator<Item = (Direction, Option<Cell<T>>)> + '_ {
+    ) -> impl Iterator<Item = (Direction, Option<Cell<T>>)> + 'a {
         directions.iter().map(move |dir| {
             let neighbour = self.neighbour(start, dir);
             (*dir, neighbour)
@@ -264,38 +341,415 @@ impl<T: Copy + Display> Matrix<T> {
     ) -> impl Iterator<Item = Cell<T>> + '_ {
         (row_start..=row_end)
             .cartesian_product(col_start..=col_end)
-            .filter_map(|(row, col)| {
-                self.get(row, col).map(|val| Cell {
-                    point: Point { col, row },
-                    val,
-                })
-            })
+            .filter_map(|(row, col)| self.get_cell(row, col))
     }
 
+    /// Transposes the matrix in place by following the cycles of the index permutation.
     pub fn transpose(&mut self) {
-        let mut res = vec![vec![]; self.rows];
+        let (rows, cols) = (self.rows, self.cols);
 
-        self.cells.iter().for_each(|row| {
-            for (i, val) in row.iter().enumerate() {
-                res[i].push(*val);
+        if rows == cols {
+            for row in 0..rows {
+                for col in row + 1..cols {
+                    self.cells.swap(row * cols + col, col * rows + row);
+                }
             }
-        });
+        } else if rows > 1 && cols > 1 {
+            let len = self.cells.len();
+            let mut visited = vec![false; len];
+
+            // the first and last cell never move.
+            for start in 1..len - 1 {
+                if visited[start] {
+                    continue;
+                }
 
-        self.cells = res;
+                let mut i = start;
+                loop {
+                    let next = (i % cols) * rows + i / cols;
+                    visited[i] = true;
+                    if next == start {
+                        break;
+                    }
+                    self.cells.swap(start, next);
+                    i = next;
+                }
+            }
+        }
+
+        self.rows = cols;
+        self.cols = rows;
     }
 
     pub fn rotate_clockwise(&mut self) {
         self.transpose();
-        self.cells.iter_mut().for_each(|row| {
-            row.reverse();
-        })
+        self.iter_rows_mut().for_each(|row| row.reverse());
     }
 
     pub fn rotate_counterclockwise(&mut self) {
-        self.cells.iter_mut().for_each(|row| {
-            row.reverse();
-        });
-
+        self.iter_rows_mut().for_each(|row| row.reverse());
         self.transpose();
     }
 }
+
+/* -------------------------------------------------------------------------- */
+
+/// A signed `(row, col)` position on a [`Tiled`] matrix.
+pub type Position = (i64, i64);
+
+/// An infinitely tiled view of a non-empty [`Matrix`]. Every position maps back into the base
+/// matrix, `(0, 0)` is the top-left cell of the base tile.
+#[derive(Debug, Clone, Copy)]
+pub struct Tiled<'a, T: Copy = char> {
+    matrix: &'a Matrix<T>,
+}
+
+impl<'a, T: Copy + Display> Tiled<'a, T> {
+    /// Maps a position to its `(row, col)` in the base matrix.
+    pub fn wrap(&self, (row, col): Position) -> (usize, usize) {
+        (
+            row.rem_euclid(self.matrix.rows as i64) as usize,
+            col.rem_euclid(self.matrix.cols as i64) as usize,
+        )
+    }
+
+    /// The `(row, col)` index of the tile a position is on, the base tile is `(0, 0)`.
+    pub fn tile(&self, (row, col): Position) -> (i64, i64) {
+        (
+            row.div_euclid(self.matrix.rows as i64),
+            col.div_euclid(self.matrix.cols as i64),
+        )
+    }
+
+    pub fn get(&self, pos: Position) -> T {
+        let (row, col) = self.wrap(pos);
+        self.matrix.cells[row * self.matrix.cols + col]
+    }
+
+    pub fn neighbour(&self, (row, col): Position, dir: &Direction) -> (Position, T) {
+        let (d_row, d_col) = dir.offset();
+        let pos = (row + d_row, col + d_col);
+        (pos, self.get(pos))
+    }
+
+    pub fn neighbours<'b>(
+        &'b self,
+        pos: Position,
+        directions: &'b [Direction],
+    ) -> impl Iterator<Item = (Direction, Position, T)> + 'b {
+        directions.iter().map(move |dir| {
+            let (next, val) = self.neighbour(pos, dir);
+            (*dir, next, val)
+        })
+    }
+
+    /// Finds the number of cardinal steps to every position within `max_steps` of `start`,
+    /// only moving onto cells for which `is_open` holds.
+    pub fn distances(
+        &self,
+        start: Position,
+        max_steps: usize,
+        is_open: impl Fn(T) -> bool,
+    ) -> HashMap<Position, usize> {
+        search::distances(
+            [start],
+            |pos| {
+                self.neighbours(*pos, &CARDINALS)
+                    .filter(|(_, _, val)| is_open(*val))
+                    .map(|(_, next, _)| next)
+                    .collect::<Vec<_>>()
+            },
+            max_steps,
+        )
+    }
+
+    /// Counts the positions that can be reached in exactly `steps` steps. Since every step can be
+    /// undone, these are the positions within reach whose distance has the same parity as `steps`.
+    pub fn reachable_in(
+        &self,
+        start: Position,
+        steps: usize,
+        is_open: impl Fn(T) -> bool,
+    ) -> usize {
+        self.distances(start, steps, is_open)
+            .values()
+            .filter(|distance| *distance % 2 == steps % 2)
+            .count()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned when parsing a [`Matrix`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseMatrixError<E> {
+    Empty,
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    Cell {
+        row: usize,
+        col: usize,
+        err: E,
+    },
+}
+
+impl<E: Error + 'static> Error for ParseMatrixError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseMatrixError::Cell { err, .. } => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl<E: Display> Display for ParseMatrixError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseMatrixError::Empty => f.write_str("expecting at least one non-empty row"),
+            ParseMatrixError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expecting row {row} to have {expected} columns, found {found}"
+            ),
+            ParseMatrixError::Cell { row, col, err } => {
+                write!(f, "could not parse cell [{row}:{col}]: {err}")
+            }
+        }
+    }
+}
+
+/// An error for `TryFrom<char>` implementations of cell types, e.g. the tiles of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidChar(pub char);
+
+impl Error for InvalidChar {}
+
+impl Display for InvalidChar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unexpected character {:?}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A borrowed, rectangular part of a [`Matrix`]. Coordinates are relative to the view.
+#[derive(Debug, Clone, Copy)]
+pub struct View<'a, T: Copy = char> {
+    matrix: &'a Matrix<T>,
+    row: usize,
+    col: usize,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl<'a, T: Copy + Display> View<'a, T> {
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        if row < self.rows && col < self.cols {
+            self.matrix.get(self.row + row, self.col + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_row(&self, row: usize) -> Option<&'a [T]> {
+        if row >= self.rows {
+            return None;
+        }
+
+        self.matrix
+            .get_row(self.row + row)
+            .map(|r| &r[self.col..self.col + self.cols])
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.rows).filter_map(|row| self.get_row(row))
+    }
+
+    /// Yields the cells of the view with their positions in the underlying matrix.
+    pub fn items(&self) -> impl Iterator<Item = Cell<T>> + '_ {
+        (self.row..self.row + self.rows)
+            .cartesian_product(self.col..self.col + self.cols)
+            .filter_map(|(row, col)| self.matrix.get_cell(row, col))
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction, InvalidChar, Matrix, ParseMatrixError};
+    use std::fmt::Display;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Path,
+        Forest,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = InvalidChar;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '.' => Ok(Tile::Path),
+                '#' => Ok(Tile::Forest),
+                _ => Err(InvalidChar(c)),
+            }
+        }
+    }
+
+    impl Display for Tile {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Tile::Path => f.write_str("."),
+                Tile::Forest => f.write_str("#"),
+            }
+        }
+    }
+
+    #[test]
+    fn stores_cells_row_major() {
+        let matrix = Matrix::from("abc\ndef\n");
+        assert_eq!((matrix.rows, matrix.cols), (2, 3));
+        assert_eq!(matrix.cells, vec!['a', 'b', 'c', 'd', 'e', 'f']);
+        assert_eq!(matrix.get(1, 0), Some('d'));
+        assert_eq!(matrix.get(0, 3), None);
+        assert_eq!(matrix.get_row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(
+            matrix.get_col(2).unwrap().rev().collect::<String>(),
+            "fc".to_string()
+        );
+        assert!(matrix.get_col(3).is_none());
+    }
+
+    #[test]
+    fn does_not_wrap_around_rows() {
+        let matrix = Matrix::from("abc\ndef\n");
+        let cell = matrix.get_cell(0, 2).unwrap();
+        assert_eq!(matrix.neighbour(&cell, &Direction::E), None);
+        assert_eq!(matrix.neighbour(&cell, &Direction::SE), None);
+        assert_eq!(
+            matrix.neighbour(&cell, &Direction::S).map(|c| c.val),
+            Some('f')
+        );
+    }
+
+    #[test]
+    fn transposes_in_place() {
+        let mut matrix: Matrix = Matrix::from("abc\ndef\n");
+        matrix.transpose();
+        assert_eq!(matrix.to_string(), "ad\nbe\ncf");
+        matrix.transpose();
+        assert_eq!(matrix.to_string(), "abc\ndef");
+
+        let mut matrix: Matrix = Matrix::from("ab\ncd\n");
+        matrix.transpose();
+        assert_eq!(matrix.to_string(), "ac\nbd");
+    }
+
+    #[test]
+    fn rotates_in_place() {
+        let mut matrix: Matrix = Matrix::from("abc\ndef\n");
+        matrix.rotate_clockwise();
+        assert_eq!(matrix.to_string(), "da\neb\nfc");
+        matrix.rotate_counterclockwise();
+        matrix.rotate_counterclockwise();
+        assert_eq!(matrix.to_string(), "cf\nbe\nad");
+    }
+
+    #[test]
+    fn borrows_views() {
+        let matrix = Matrix::from("abcd\nefgh\nijkl\n");
+        let view = matrix.view(1, 1, 2, 2).unwrap();
+        assert_eq!(view.get(0, 0), Some('f'));
+        assert_eq!(view.get(0, 2), None);
+        assert_eq!(view.get_row(1), Some(&['j', 'k'][..]));
+        assert_eq!(view.items().map(|c| c.val).collect::<String>(), "fgjk");
+        assert!(matrix.view(2, 2, 2, 2).is_none());
+    }
+
+    #[test]
+    fn parses_cells_with_try_from() {
+        let matrix: Matrix<Tile> = "#.#\n..#\n".parse().unwrap();
+        assert_eq!(matrix.get(1, 0), Some(Tile::Path));
+        assert_eq!(matrix.get(1, 2), Some(Tile::Forest));
+        assert_eq!(matrix.to_string(), "#.#\n..#");
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        assert_eq!(
+            "#.#\n.x#\n".parse::<Matrix<Tile>>(),
+            Err(ParseMatrixError::Cell {
+                row: 1,
+                col: 1,
+                err: InvalidChar('x')
+            })
+        );
+        assert_eq!(
+            "#.#\n.#\n".parse::<Matrix<Tile>>(),
+            Err(ParseMatrixError::Ragged {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Matrix::parse_with("\n", |c| c.to_digit(10).ok_or(InvalidChar(c))),
+            Err(ParseMatrixError::Empty)
+        );
+        assert_eq!(
+            Matrix::parse_with("12\n3a\n", |c| c.to_digit(10).ok_or(InvalidChar(c)))
+                .unwrap_err()
+                .to_string(),
+            "could not parse cell [1:1]: unexpected character 'a'"
+        );
+    }
+
+    #[test]
+    fn wraps_tiled_positions() {
+        let matrix: Matrix = Matrix::from("ab\ncd\n");
+        let tiled = matrix.tiled().unwrap();
+        assert_eq!(tiled.get((0, 0)), 'a');
+        assert_eq!(tiled.get((-1, -1)), 'd');
+        assert_eq!(tiled.get((5, -4)), 'c');
+        assert_eq!(tiled.wrap((-3, 2)), (1, 0));
+        assert_eq!(tiled.tile((-3, 2)), (-2, 1));
+        assert_eq!(tiled.neighbour((0, 0), &Direction::NW), ((-1, -1), 'd'));
+
+        let empty: Matrix = Matrix {
+            cells: vec![],
+            cols: 0,
+            rows: 0,
+        };
+        assert!(empty.tiled().is_none());
+    }
+
+    #[test]
+    fn searches_across_tiles() {
+        let matrix: Matrix = Matrix::from(
+            "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+",
+        );
+        let tiled = matrix.tiled().unwrap();
+        let is_open = |c| c != '#';
+
+        assert_eq!(tiled.reachable_in((5, 5), 6, is_open), 16);
+        assert_eq!(tiled.reachable_in((5, 5), 10, is_open), 50);
+        assert_eq!(tiled.reachable_in((5, 5), 50, is_open), 1594);
+    }
+}
@@ -0,0 +1,210 @@
+//! Compresses grid mazes into weighted graphs of their junctions.
+
+use hashbrown::HashMap;
+use std::fmt::Display;
+
+use super::{
+    matrix::{Cell, Matrix},
+    point::Point2,
+    search::flood_fill,
+};
+
+/// A maze reduced to the cells where paths meet, connected by the corridors between them.
+/// Corridors are directed, so one-way cells like slopes are kept intact.
+#[derive(Debug, Clone)]
+pub struct JunctionGraph {
+    pub nodes: Vec<Point2<usize>>,
+    /// Outgoing `(node, length)` edges of every node.
+    pub edges: Vec<Vec<(usize, usize)>>,
+}
+
+impl JunctionGraph {
+    /// Builds the graph of the maze that is reachable from `points`. Every cell with more than two
+    /// successors is a junction, `points` are added as nodes as well, e.g. the entrance and the exit.
+    pub fn new<T, I>(
+        matrix: &Matrix<T>,
+        points: &[Point2<usize>],
+        successors: impl Fn(&Cell<T>) -> I,
+    ) -> Self
+    where
+        T: Copy + Display,
+        I: IntoIterator<Item = Cell<T>>,
+    {
+        let mut nodes: Vec<Point2<usize>> = points.to_vec();
+
+        let starts = points
+            .iter()
+            .filter_map(|point| matrix.get_cell(point.y(), point.x()));
+
+        let mut junctions: Vec<Point2<usize>> = flood_fill(starts, &successors)
+            .into_iter()
+            .filter(|cell| {
+                !points.contains(&cell.point) && successors(cell).into_iter().count() > 2
+            })
+            .map(|cell| cell.point)
+            .collect();
+
+        junctions.sort_by_key(|point| (point.y(), point.x()));
+        nodes.extend(junctions);
+
+        let indices: HashMap<Point2<usize>, usize> =
+            nodes.iter().enumerate().map(|(i, p)| (*p, i)).collect();
+
+        let edges = nodes
+            .iter()
+            .map(|point| {
+                let mut edges: Vec<(usize, usize)> = vec![];
+                let Some(node) = matrix.get_cell(point.y(), point.x()) else {
+                    return edges;
+                };
+
+                for first in successors(&node) {
+                    let Some((to, len)) = walk_corridor(node, first, &indices, &successors) else {
+                        continue;
+                    };
+
+                    // keep the longest of parallel corridors.
+                    match edges.iter_mut().find(|(n, _)| *n == to) {
+                        Some(edge) => edge.1 = edge.1.max(len),
+                        None => edges.push((to, len)),
+                    }
+                }
+
+                edges
+            })
+            .collect();
+
+        Self { nodes, edges }
+    }
+
+    pub fn index_of(&self, point: &Point2<usize>) -> Option<usize> {
+        self.nodes.iter().position(|p| p == point)
+    }
+
+    /// Finds the length of the longest path from `start` to `goal` that visits every node at most
+    /// once, with a depth-first search that tracks visited nodes in a bitmask.
+    ///
+    /// # Panics
+    ///
+    /// If the graph has more than 64 nodes.
+    pub fn longest_path(&self, start: usize, goal: usize) -> Option<usize> {
+        assert!(
+            self.nodes.len() <= 64,
+            "expecting at most 64 junctions, found {}",
+            self.nodes.len()
+        );
+
+        // once the only node leading to the goal is reached, any other move can never reach it.
+        let mut predecessors = (0..self.nodes.len())
+            .filter(|n| *n != goal && self.edges[*n].iter().any(|(to, _)| *to == goal));
+        let last = match (predecessors.next(), predecessors.next()) {
+            (Some(n), None) => Some(n),
+            _ => None,
+        };
+
+        self.dfs(start, goal, last, 1 << start)
+    }
+
+    fn dfs(&self, node: usize, goal: usize, last: Option<usize>, visited: u64) -> Option<usize> {
+        if node == goal {
+            return Some(0);
+        }
+
+        let edges = &self.edges[node];
+
+        if Some(node) == last {
+            return edges
+                .iter()
+                .find(|(to, _)| *to == goal)
+                .map(|(_, len)| *len);
+        }
+
+        edges
+            .iter()
+            .filter(|(to, _)| visited & (1 << to) == 0)
+            .filter_map(|(to, len)| {
+                self.dfs(*to, goal, last, visited | (1 << to))
+                    .map(|rest| rest + len)
+            })
+            .max()
+    }
+}
+
+/// Follows a corridor from `node` through `first` until it reaches another node.
+/// Returns `None` for dead ends and corridors that can not be traversed in this direction.
+fn walk_corridor<T, I>(
+    node: Cell<T>,
+    first: Cell<T>,
+    indices: &HashMap<Point2<usize>, usize>,
+    successors: &impl Fn(&Cell<T>) -> I,
+) -> Option<(usize, usize)>
+where
+    T: Copy + Display,
+    I: IntoIterator<Item = Cell<T>>,
+{
+    let mut prev = node;
+    let mut current = first;
+    let mut len = 1;
+
+    loop {
+        if let Some(to) = indices.get(&current.point) {
+            return (*to != indices[&node.point]).then_some((*to, len));
+        }
+
+        let mut next = successors(&current).into_iter().filter(|c| *c != prev);
+
+        match (next.next(), next.next()) {
+            (Some(cell), None) => {
+                prev = current;
+                current = cell;
+                len += 1;
+            }
+            _ => return None,
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::JunctionGraph;
+    use crate::helpers::{
+        matrix::{Matrix, CARDINALS},
+        point::Point2,
+        search::cells,
+    };
+
+    const MAZE: &str = "#.#####
+#.....#
+#.#.#.#
+#.....#
+#####.#
+";
+
+    fn graph(matrix: &Matrix) -> JunctionGraph {
+        let points = [Point2::new(1, 0), Point2::new(5, 4)];
+        JunctionGraph::new(matrix, &points, |cell| {
+            cells(matrix, cell, &CARDINALS)
+                .filter(|c| c.val != '#')
+                .collect::<Vec<_>>()
+        })
+    }
+
+    #[test]
+    fn compresses_corridors() {
+        let matrix: Matrix = Matrix::from(MAZE);
+        let graph = graph(&matrix);
+
+        // entrance, exit and the junctions at [1:1], [1:3], [3:3] and [3:5].
+        assert_eq!(graph.nodes.len(), 6);
+        let junction = graph.index_of(&Point2::new(1, 1)).unwrap();
+        assert_eq!(graph.edges[junction].len(), 3);
+        assert!(graph.edges[0].contains(&(junction, 1)));
+    }
+
+    #[test]
+    fn finds_longest_simple_path() {
+        let matrix: Matrix = Matrix::from(MAZE);
+        let graph = graph(&matrix);
+        assert_eq!(graph.longest_path(0, 1), Some(12));
+    }
+}
@@ -1,3 +1,10 @@
-pub mod grid;
+pub mod cycle;
+pub mod geometry;
+pub mod intervals;
+pub mod linalg;
 pub mod math;
 pub mod matrix;
+pub mod maze;
+pub mod parse;
+pub mod point;
+pub mod search;
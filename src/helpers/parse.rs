@@ -0,0 +1,364 @@
+//! Small parser combinators for puzzle inputs. A [`Cursor`] walks the input and remembers where
+//! it is, so that malformed input is reported with its line and column instead of being skipped.
+
+use std::{error::Error, fmt::Display, str::FromStr};
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+/// A position in the input, which only sees the input up to `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor<'a> {
+    source: &'a str,
+    pos: usize,
+    end: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            pos: 0,
+            end: source.len(),
+        }
+    }
+
+    /// The input that has not been parsed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.pos..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.end
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// The line and column of the cursor, both starting at 1.
+    pub fn position(&self) -> (usize, usize) {
+        let before = &self.source[..self.pos];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+
+    /// An error at the current position, `expected` describes what should have been here.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let found = match self.peek() {
+            Some(c) => format!("'{c}'"),
+            None => self.end_name().into(),
+        };
+
+        self.error_found(expected, found)
+    }
+
+    fn error_found(&self, expected: impl Into<String>, found: impl Into<String>) -> ParseError {
+        let (line, col) = self.position();
+
+        ParseError {
+            line,
+            col,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    fn end_name(&self) -> &'static str {
+        if self.end == self.source.len() {
+            "end of input"
+        } else {
+            "end of line"
+        }
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let taken = &self.rest()[..len];
+        self.pos += len;
+        taken
+    }
+
+    /// Consumes the longest prefix of characters for which `pred` holds, which may be empty.
+    pub fn take_while(&mut self, pred: impl Fn(char) -> bool) -> &'a str {
+        let len = self.rest().find(|c| !pred(c)).unwrap_or(self.rest().len());
+        self.advance(len)
+    }
+
+    /// Skips spaces and tabs, but not line breaks.
+    pub fn spaces(&mut self) -> &'a str {
+        self.take_while(|c| c == ' ' || c == '\t')
+    }
+
+    pub fn tag(&mut self, tag: &str) -> ParseResult<&'a str> {
+        if self.rest().starts_with(tag) {
+            Ok(self.advance(tag.len()))
+        } else {
+            Err(self.error(format!("'{tag}'")))
+        }
+    }
+
+    /// Consumes `sep` with optional spaces around it. A separator that only consists of spaces
+    /// matches any number of spaces, but at least one.
+    pub fn delimiter(&mut self, sep: &str) -> ParseResult<()> {
+        let checkpoint = *self;
+        let spaces = self.spaces();
+
+        let result = match sep.trim() {
+            "" if spaces.is_empty() => Err(self.error("a space")),
+            "" => Ok(()),
+            sep => self.tag(sep).map(|_| {
+                self.spaces();
+            }),
+        };
+
+        if result.is_err() {
+            *self = checkpoint;
+        }
+        result
+    }
+
+    /// Consumes letters, digits and underscores, at least one of them.
+    pub fn word(&mut self) -> ParseResult<&'a str> {
+        match self.take_while(|c| c.is_alphanumeric() || c == '_') {
+            "" => Err(self.error("a word")),
+            word => Ok(word),
+        }
+    }
+
+    /// Consumes an integer with an optional sign.
+    pub fn integer<T: FromStr>(&mut self) -> ParseResult<T> {
+        let start = *self;
+        let sign = self.rest().starts_with(['+', '-']) as usize;
+        let digits = self.rest()[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest().len() - sign);
+
+        if digits == 0 {
+            return Err(self.error("an integer"));
+        }
+
+        self.advance(sign + digits)
+            .parse()
+            .map_err(|_| start.error(format!("an integer that fits into {}", type_name::<T>())))
+    }
+
+    /// Consumes the first of `options` that the input starts with and returns its value.
+    pub fn one_of<T: Copy>(&mut self, options: &[(&str, T)]) -> ParseResult<T> {
+        for (tag, val) in options {
+            if self.tag(tag).is_ok() {
+                return Ok(*val);
+            }
+        }
+
+        let tags: Vec<String> = options.iter().map(|(tag, _)| format!("'{tag}'")).collect();
+        Err(self.error(format!("one of {}", tags.join(", "))))
+    }
+
+    /// Parses one or more items with `item`, separated by `sep` as in [`Cursor::delimiter`].
+    pub fn separated<T>(
+        &mut self,
+        sep: &str,
+        mut item: impl FnMut(&mut Self) -> ParseResult<T>,
+    ) -> ParseResult<Vec<T>> {
+        let mut items = vec![item(self)?];
+
+        loop {
+            let checkpoint = *self;
+
+            // a trailing separator made of spaces is not followed by another item.
+            if self.delimiter(sep).is_err() || self.is_empty() || self.peek() == Some('\n') {
+                *self = checkpoint;
+                return Ok(items);
+            }
+
+            items.push(item(self)?);
+        }
+    }
+
+    /// Like [`Cursor::separated`], but expects exactly `N` items.
+    pub fn array<T, const N: usize>(
+        &mut self,
+        sep: &str,
+        item: impl FnMut(&mut Self) -> ParseResult<T>,
+    ) -> ParseResult<[T; N]> {
+        let start = *self;
+        self.separated(sep, item)?
+            .try_into()
+            .map_err(|items: Vec<T>| {
+                start.error_found(format!("{N} items"), items.len().to_string())
+            })
+    }
+
+    /// Parses a block of `key=value` pairs, where `assign` is the `=` and `sep` separates the
+    /// pairs. Keys are words, values are parsed with `value`.
+    pub fn key_values<T>(
+        &mut self,
+        sep: &str,
+        assign: &str,
+        mut value: impl FnMut(&mut Self) -> ParseResult<T>,
+    ) -> ParseResult<Vec<(&'a str, T)>> {
+        self.separated(sep, |c| {
+            let key = c.word()?;
+            c.delimiter(assign)?;
+            Ok((key, value(c)?))
+        })
+    }
+
+    /// Expects that everything has been parsed, apart from trailing whitespace.
+    pub fn end(&mut self) -> ParseResult<()> {
+        self.take_while(char::is_whitespace);
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(self.error(self.end_name())),
+        }
+    }
+
+    /// Consumes the next line, which is parsed with the returned cursor.
+    pub fn line(&mut self) -> ParseResult<Cursor<'a>> {
+        if self.is_empty() {
+            return Err(self.error("another line"));
+        }
+
+        let len = self.rest().find('\n').unwrap_or(self.rest().len());
+        let line = Cursor {
+            source: self.source,
+            pos: self.pos,
+            end: self.pos + len,
+        };
+
+        self.advance((len + 1).min(self.rest().len()));
+        Ok(line)
+    }
+
+    /// Parses every remaining non-blank line with `parse_line`, which has to consume all of it.
+    pub fn lines<T>(
+        &mut self,
+        mut parse_line: impl FnMut(&mut Cursor<'a>) -> ParseResult<T>,
+    ) -> ParseResult<Vec<T>> {
+        let mut items = vec![];
+
+        while !self.is_empty() {
+            let mut line = self.line()?;
+            if line.rest().trim().is_empty() {
+                continue;
+            }
+
+            items.push(parse_line(&mut line)?);
+            line.end()?;
+        }
+
+        Ok(items)
+    }
+
+    /// Splits the remaining input into sections that are separated by blank lines.
+    pub fn sections(&mut self) -> Vec<Cursor<'a>> {
+        let mut sections: Vec<Cursor<'a>> = vec![];
+        let mut current: Option<Cursor<'a>> = None;
+
+        while let Ok(line) = self.line() {
+            match (&mut current, line.rest().trim().is_empty()) {
+                (Some(section), false) => section.end = line.end,
+                (None, false) => current = Some(line),
+                (_, true) => sections.extend(current.take()),
+            }
+        }
+
+        sections.extend(current);
+        sections
+    }
+}
+
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned when parsing with a [`Cursor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub col: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expecting {} at {}:{}, found {}",
+            self.expected, self.line, self.col, self.found
+        )
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Cursor, ParseError};
+
+    #[test]
+    fn parses_integers_and_lists() {
+        let mut c = Cursor::new("-12, +3,4  7   8");
+        assert_eq!(c.array(",", Cursor::integer), Ok([-12, 3, 4]));
+        c.spaces();
+        assert_eq!(c.separated(" ", Cursor::integer::<u8>), Ok(vec![7, 8]));
+        assert!(c.is_empty());
+
+        let mut c = Cursor::new("300");
+        assert_eq!(
+            c.integer::<u8>().unwrap_err().expected,
+            "an integer that fits into u8"
+        );
+    }
+
+    #[test]
+    fn parses_key_values_and_sections() {
+        let input = "a\nb\n\n\n{x=1,m=-2}\n";
+        let sections = Cursor::new(input).sections();
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].rest(), "a\nb");
+
+        let mut part = sections[1];
+        part.tag("{").unwrap();
+        assert_eq!(
+            part.key_values(",", "=", Cursor::integer),
+            Ok(vec![("x", 1), ("m", -2)])
+        );
+        assert_eq!(part.tag("}"), Ok("}"));
+        assert_eq!(part.end(), Ok(()));
+    }
+
+    #[test]
+    fn reports_positions() {
+        let input = "1 2\n3 x\n";
+        let err = Cursor::new(input)
+            .lines(|c| c.separated(" ", Cursor::integer::<u32>))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                line: 2,
+                col: 3,
+                expected: "an integer".into(),
+                found: "'x'".into(),
+            }
+        );
+        assert_eq!(err.to_string(), "expecting an integer at 2:3, found 'x'");
+
+        let err = Cursor::new("1 2 3\n")
+            .lines(|c| c.array::<u32, 2>(" ", Cursor::integer))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "expecting 2 items at 1:1, found 3");
+
+        let err = Cursor::new("red\n")
+            .lines(|c| c.one_of(&[("blue", 0), ("green", 1)]))
+            .unwrap_err();
+        assert_eq!(err.expected, "one of 'blue', 'green'");
+    }
+}
@@ -0,0 +1,284 @@
+//! Points and vectors in two or three dimensions.
+//!
+//! The y-axis points down, like the rows of a [`Matrix`](super::matrix::Matrix), so that
+//! `Direction::S` is `(0, 1)`.
+
+use std::{
+    array,
+    error::Error,
+    fmt::Display,
+    ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use super::matrix::Direction;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T = i64, const N: usize = 2>(pub [T; N]);
+
+pub type Point2<T = i64> = Point<T, 2>;
+
+pub type Point3<T = i64> = Point<T, 3>;
+
+impl<T: Copy> Point<T, 2> {
+    pub fn new(x: T, y: T) -> Self {
+        Self([x, y])
+    }
+
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+}
+
+impl<T: Copy> Point<T, 3> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self([x, y, z])
+    }
+
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> T {
+        self.0[2]
+    }
+}
+
+impl<T, const N: usize> Point<T, N>
+where
+    T: Copy + Default + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn origin() -> Self {
+        Self([T::default(); N])
+    }
+
+    fn distances<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = T> + 'a {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .map(|(&a, &b)| if a > b { a - b } else { b - a })
+    }
+
+    /// The distance when moving along the axes, also known as taxicab distance.
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.distances(other).fold(T::default(), |acc, d| acc + d)
+    }
+
+    /// The distance when diagonal moves are allowed, also known as chessboard distance.
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.distances(other).max().unwrap_or_default()
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Point<T, 2> {
+    pub fn rotate_clockwise(&self) -> Self {
+        Self([-self.y(), self.x()])
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self([self.y(), -self.x()])
+    }
+}
+
+impl Point<i64, 2> {
+    /// Converts an index into the cells of a matrix with `cols` columns.
+    pub fn from_index(index: usize, cols: usize) -> Self {
+        Self([(index % cols) as i64, (index / cols) as i64])
+    }
+
+    /// The index into the cells of a matrix with `cols` columns, if the point is within them.
+    pub fn to_index(&self, cols: usize) -> Option<usize> {
+        let col: usize = self.x().try_into().ok()?;
+        let row: usize = self.y().try_into().ok()?;
+        (col < cols).then(|| row * cols + col)
+    }
+}
+
+impl From<Point<usize, 2>> for Point<i64, 2> {
+    fn from(point: Point<usize, 2>) -> Self {
+        Self(point.0.map(|c| c as i64))
+    }
+}
+
+impl From<Direction> for Point<i64, 2> {
+    fn from(dir: Direction) -> Self {
+        let (row, col) = dir.offset();
+        Self([col, row])
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl<T: Copy + Add<Output = T>, const N: usize> Add for Point<T, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<T: Copy + Sub<Output = T>, const N: usize> Sub for Point<T, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<T: Copy + Mul<Output = T>, const N: usize> Mul<T> for Point<T, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self(self.0.map(|v| v * rhs))
+    }
+}
+
+impl<T: Copy + Neg<Output = T>, const N: usize> Neg for Point<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(self.0.map(|v| -v))
+    }
+}
+
+impl<T: Copy + Add<Output = T>, const N: usize> AddAssign for Point<T, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>, const N: usize> SubAssign for Point<T, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Point<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Point<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<T: Display, const N: usize> Display for Point<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{v}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses comma-separated coordinates like `1,0,-1`.
+impl<T: Copy + Default + FromStr, const N: usize> FromStr for Point<T, N> {
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut coords = [T::default(); N];
+        let mut parts = s.split(',');
+
+        for coord in coords.iter_mut() {
+            *coord = parts
+                .next()
+                .and_then(|p| p.trim().parse().ok())
+                .ok_or(ParsePointError)?;
+        }
+
+        match parts.next() {
+            Some(_) => Err(ParsePointError),
+            None => Ok(Self(coords)),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Point`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePointError;
+
+impl Error for ParsePointError {}
+
+impl Display for ParsePointError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting comma-separated coordinates")
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Point, Point2, Point3};
+    use crate::helpers::matrix::{Direction, Matrix};
+
+    #[test]
+    fn supports_arithmetic() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(3, 4);
+        assert_eq!(a + b, Point2::new(4, 2));
+        assert_eq!(a - b, Point2::new(-2, -6));
+        assert_eq!(b * 3, Point2::new(9, 12));
+        assert_eq!(-a, Point2::new(-1, 2));
+
+        let mut c = Point3::new(1u32, 2, 3);
+        c -= Point3::new(0, 0, 1);
+        assert_eq!(c, Point3::new(1, 2, 2));
+    }
+
+    #[test]
+    fn measures_distances() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(3, 4);
+        assert_eq!(a.manhattan(&b), 8);
+        assert_eq!(a.chebyshev(&b), 6);
+        assert_eq!(Point3::new(1u32, 5, 3).manhattan(&Point3::new(4, 2, 3)), 6);
+    }
+
+    #[test]
+    fn moves_and_rotates() {
+        let start = Point2::new(2, 2);
+        assert_eq!(start + Point::from(Direction::N) * 2, Point2::new(2, 0));
+        assert_eq!(start + Point::from(Direction::SE), Point2::new(3, 3));
+
+        let east = Point::from(Direction::E);
+        assert_eq!(east.rotate_clockwise(), Point::from(Direction::S));
+        assert_eq!(east.rotate_counterclockwise(), Point::from(Direction::N));
+    }
+
+    #[test]
+    fn converts_matrix_indices() {
+        let point = Point::from_index(7, 3);
+        assert_eq!(point, Point2::new(1, 2));
+        assert_eq!(point.to_index(3), Some(7));
+        assert_eq!(point.to_index(1), None);
+        assert_eq!(Point2::new(-1, 0).to_index(3), None);
+
+        let matrix: Matrix = Matrix::from("abc\ndef\nghi\n");
+        let cell = matrix.items().nth(7).unwrap();
+        assert_eq!(cell.point, Point2::new(1usize, 2));
+        assert_eq!(Point::from(cell.point), point);
+    }
+
+    #[test]
+    fn parses_coordinates() {
+        assert_eq!("1,0,-1".parse(), Ok(Point3::new(1, 0, -1)));
+        assert!("1,0".parse::<Point3>().is_err());
+        assert!("1,0,2,3".parse::<Point3>().is_err());
+        assert_eq!(Point2::new(1, 2).to_string(), "1,2");
+    }
+}
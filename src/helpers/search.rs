@@ -0,0 +1,387 @@
+//! Graph searches that are generic over the state type. Neighbouring states are produced by a
+//! successor closure, which makes it possible to search grids, state machines and graphs alike.
+
+use hashbrown::{hash_map::Entry, HashMap, HashSet};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    fmt::Display,
+    hash::Hash,
+    ops::Add,
+};
+
+use super::matrix::{Cell, Direction, Matrix};
+
+/// A path found by a search, from one of the start states to a goal state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C = usize> {
+    pub states: Vec<S>,
+    pub cost: C,
+}
+
+/// Visited states with the index of the state they were reached from.
+struct Parents<S> {
+    states: Vec<(S, Option<usize>)>,
+    indices: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash> Parents<S> {
+    fn new() -> Self {
+        Self {
+            states: vec![],
+            indices: HashMap::new(),
+        }
+    }
+
+    fn path(&self, mut index: usize) -> Vec<S> {
+        let mut path = vec![];
+
+        loop {
+            let (state, parent) = &self.states[index];
+            path.push(state.clone());
+            match parent {
+                Some(parent) => index = *parent,
+                None => break,
+            }
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Finds a path with the fewest steps from any of `starts` to a state for which `is_goal` holds.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents = Parents::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if !parents.indices.contains_key(&start) {
+            parents.indices.insert(start.clone(), parents.states.len());
+            queue.push_back(parents.states.len());
+            parents.states.push((start, None));
+        }
+    }
+
+    while let Some(index) = queue.pop_front() {
+        let state = parents.states[index].0.clone();
+
+        if is_goal(&state) {
+            let states = parents.path(index);
+            return Some(Path {
+                cost: states.len() - 1,
+                states,
+            });
+        }
+
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = parents.indices.entry(next.clone()) {
+                entry.insert(parents.states.len());
+                queue.push_back(parents.states.len());
+                parents.states.push((next, Some(index)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds the fewest steps from any of `starts` to every state that is at most `max_steps` away.
+pub fn distances<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    max_steps: usize,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((state, steps)) = queue.pop_front() {
+        if steps == max_steps {
+            continue;
+        }
+
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(steps + 1);
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+/// Finds every state that is reachable from any of `starts`, including the starts themselves.
+pub fn flood_fill<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> HashSet<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = HashSet::new();
+    let mut stack = vec![];
+
+    for start in starts {
+        if visited.insert(start.clone()) {
+            stack.push(start);
+        }
+    }
+
+    while let Some(state) = stack.pop() {
+        for next in successors(&state) {
+            if visited.insert(next.clone()) {
+                stack.push(next);
+            }
+        }
+    }
+
+    visited
+}
+
+/// Finds a path with the lowest cost from any of `starts` to a state for which `is_goal` holds.
+/// `successors` yields neighbouring states together with the cost of moving to them.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but explores states in order of their cost plus `heuristic`, which estimates
+/// the remaining cost to a goal. The path is only guaranteed to be the cheapest one if the
+/// heuristic never overestimates.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut parents = Parents::new();
+    let mut costs: Vec<C> = vec![];
+    let mut frontier = BinaryHeap::new();
+
+    for start in starts {
+        if !parents.indices.contains_key(&start) {
+            let index = parents.states.len();
+            frontier.push(Reverse((heuristic(&start), C::default(), index)));
+            parents.indices.insert(start.clone(), index);
+            parents.states.push((start, None));
+            costs.push(C::default());
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = frontier.pop() {
+        // a cheaper way to this state was found after this entry was queued.
+        if cost > costs[index] {
+            continue;
+        }
+
+        let state = parents.states[index].0.clone();
+
+        if is_goal(&state) {
+            return Some(Path {
+                states: parents.path(index),
+                cost,
+            });
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+
+            let next_index = match parents.indices.entry(next.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(parents.states.len());
+                    parents.states.push((next.clone(), Some(index)));
+                    costs.push(next_cost);
+                    parents.states.len() - 1
+                }
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    if next_cost >= costs[next_index] {
+                        continue;
+                    }
+                    parents.states[next_index].1 = Some(index);
+                    costs[next_index] = next_cost;
+                    next_index
+                }
+            };
+
+            frontier.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                next_index,
+            )));
+        }
+    }
+
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Yields the neighbours of `cell` in `directions` that are on the matrix.
+pub fn cells<'a, T: Copy + Display>(
+    matrix: &'a Matrix<T>,
+    cell: &Cell<T>,
+    directions: &'a [Direction],
+) -> impl Iterator<Item = Cell<T>> + 'a {
+    let cell = *cell;
+    directions
+        .iter()
+        .filter_map(move |dir| matrix.neighbour(&cell, dir))
+}
+
+/// A search state for puzzles where the direction of travel matters, e.g. beams or carts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Heading<T: Copy + Display = char> {
+    pub cell: Cell<T>,
+    pub dir: Direction,
+}
+
+impl<T: Copy + Display> Heading<T> {
+    pub fn new(cell: Cell<T>, dir: Direction) -> Self {
+        Self { cell, dir }
+    }
+
+    /// Steps to the neighbouring cell in `dir`, facing that direction.
+    pub fn advance(&self, matrix: &Matrix<T>, dir: Direction) -> Option<Self> {
+        matrix
+            .neighbour(&self.cell, &dir)
+            .map(|cell| Self { cell, dir })
+    }
+
+    /// Steps forward, left or right, never turning back.
+    pub fn moves<'a>(&self, matrix: &'a Matrix<T>) -> impl Iterator<Item = Self> + 'a {
+        let heading = *self;
+        [self.dir, self.dir.turn_left(), self.dir.turn_right()]
+            .into_iter()
+            .filter_map(move |dir| heading.advance(matrix, dir))
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, cells, dijkstra, distances, flood_fill, Heading};
+    use crate::helpers::matrix::{Direction, Matrix, CARDINALS};
+
+    const MAZE: &str = "#.#####
+#.....#
+#.###.#
+#...#.#
+###.#.#
+#.....#
+#####.#
+";
+
+    #[test]
+    fn finds_shortest_paths_on_grids() {
+        let matrix: Matrix = Matrix::from(MAZE);
+        let start = matrix.get_cell(0, 1).unwrap();
+        let goal = matrix.get_cell(6, 5).unwrap();
+
+        let path = bfs(
+            [start],
+            |cell| cells(&matrix, cell, &CARDINALS).filter(|c| c.val != '#'),
+            |cell| *cell == goal,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 10);
+        assert_eq!(path.states.first(), Some(&start));
+        assert_eq!(path.states.last(), Some(&goal));
+        assert!(path
+            .states
+            .windows(2)
+            .all(|w| w[0].point.manhattan(&w[1].point) == 1));
+    }
+
+    #[test]
+    fn finds_cheapest_paths() {
+        // 0 -> 1 -> 3 costs 4, the direct edge costs 5 and 0 -> 2 -> 3 costs 6.
+        let edges = |n: &u32| match n {
+            0 => vec![(1, 1), (2, 1), (3, 5)],
+            1 => vec![(3, 3)],
+            2 => vec![(3, 5)],
+            _ => vec![],
+        };
+
+        let path = dijkstra([0], edges, |n| *n == 3).unwrap();
+        assert_eq!(path.states, vec![0, 1, 3]);
+        assert_eq!(path.cost, 4);
+
+        let path = astar([0], edges, |n| 3 - n, |n| *n == 3).unwrap();
+        assert_eq!(path.states, vec![0, 1, 3]);
+        assert_eq!(path.cost, 4);
+
+        assert_eq!(dijkstra([1], edges, |n| *n == 0), None);
+    }
+
+    #[test]
+    fn fills_reachable_states() {
+        let matrix: Matrix = Matrix::from(MAZE);
+        let start = matrix.get_cell(1, 1).unwrap();
+        let filled = flood_fill([start], |cell| {
+            cells(&matrix, cell, &CARDINALS).filter(|c| c.val != '#')
+        });
+        assert_eq!(
+            filled.len(),
+            matrix.cells.iter().filter(|c| **c != '#').count()
+        );
+    }
+
+    #[test]
+    fn measures_distances() {
+        let successors = |n: &u32| [n + 1, n * 2];
+        let found = distances([1], successors, 2);
+        assert_eq!(found.len(), 4);
+        assert_eq!(found[&1], 0);
+        assert_eq!(found[&2], 1);
+        assert_eq!(found[&4], 2);
+        assert_eq!(found[&3], 2);
+    }
+
+    #[test]
+    fn moves_headings() {
+        let matrix: Matrix = Matrix::from("abc\ndef\n");
+        let heading = Heading::new(matrix.get_cell(0, 1).unwrap(), Direction::E);
+        let moves: Vec<(char, Direction)> = heading
+            .moves(&matrix)
+            .map(|h| (h.cell.val, h.dir))
+            .collect();
+        assert_eq!(moves, vec![('c', Direction::E), ('e', Direction::S)]);
+    }
+}
@@ -1,77 +1,215 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, examples, read, scaffold, solve, time, verify, watch,
+};
+use advent_of_code::template::registry::Solution;
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::{Day, PuzzleId, Year};
 #[cfg(feature = "today")]
 use std::process;
 
+/// Solutions compiled into this binary, see `build.rs`.
+#[cfg(all(feature = "in-process", not(test)))]
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
+fn registry() -> &'static [Solution] {
+    #[cfg(all(feature = "in-process", not(test)))]
+    return solutions::SOLUTIONS;
+
+    #[cfg(not(all(feature = "in-process", not(test))))]
+    return &[];
+}
+
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{input::InputSource, Day, PuzzleId, Year};
     use std::process;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
+        },
+        Examples {
+            puzzle: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
             download: bool,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
+            input: InputSource,
             release: bool,
             dhat: bool,
+            json: bool,
             submit: Option<u8>,
+            watch: bool,
         },
         All {
+            year: Year,
+            input: InputSource,
             release: bool,
         },
         Time {
+            year: Year,
+            input: InputSource,
             all: bool,
             day: Option<Day>,
             store: bool,
+            budget: Option<u64>,
+            compare: bool,
+            threshold: Option<f64>,
+        },
+        Verify {
+            year: Year,
+            day: Option<Day>,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
+    /// Reads the `--year` option, falling back to the `AOC_YEAR` environment variable.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => Year::from_env()
+                .ok_or_else(|| "no year specified. Pass `--year <year>` or set `AOC_YEAR`.".into()),
+        }
+    }
+
+    /// Reads the `--year` option and a free-standing day argument.
+    fn parse_puzzle(
+        args: &mut pico_args::Arguments,
+    ) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        let year = parse_year(args)?;
+        Ok(PuzzleId::new(year, args.free_from_str()?))
+    }
+
+    /// Reads the `--input <path>` option, or `--example` and `--example=<k>`. The number of an
+    /// example is only read after a `=`, so that it can not be mistaken for a day.
+    fn parse_input(
+        args: &mut pico_args::Arguments,
+    ) -> Result<InputSource, Box<dyn std::error::Error>> {
+        let input_path: Option<String> = args.opt_value_from_str("--input")?;
+        let example: Option<Option<u8>> = if args.contains("--example") {
+            Some(None)
+        } else {
+            args.opt_value_from_str("--example")?.map(Some)
+        };
+
+        match (input_path, example) {
+            (Some(_), Some(_)) => Err("`--input` and `--example` can not be combined.".into()),
+            (Some(path), None) => Ok(InputSource::from_path(&path)),
+            (None, Some(k)) => Ok(InputSource::Example(k)),
+            (None, None) => Ok(InputSource::Puzzle),
+        }
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
+            Some("all") => {
+                let release = args.contains("--release");
+                let input = parse_input(&mut args)?;
+
+                if matches!(input, InputSource::File(_) | InputSource::Stdin) {
+                    return Err(
+                        "`--input` selects the input of a single day, use `solve` instead.".into(),
+                    );
+                }
+
+                AppArguments::All {
+                    year: parse_year(&mut args)?,
+                    input,
+                    release,
+                }
+            }
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let budget = args.opt_value_from_str("--budget")?;
+                let compare = args.contains("--compare");
+                let threshold = args.opt_value_from_str("--threshold")?;
+                let input = parse_input(&mut args)?;
+                let year = parse_year(&mut args)?;
+                let day = args.opt_free_from_str()?;
+
+                if matches!(input, InputSource::File(_) | InputSource::Stdin) && day.is_none() {
+                    return Err(
+                        "`--input` selects the input of a single day, pass `time <day>`.".into(),
+                    );
+                }
+
+                if input != InputSource::Puzzle && (store || compare) {
+                    return Err(
+                        "`--store` and `--compare` can only be used with the puzzle input.".into(),
+                    );
+                }
 
                 AppArguments::Time {
+                    year,
+                    input,
                     all,
-                    day: args.opt_free_from_str()?,
+                    day,
                     store,
+                    budget,
+                    compare,
+                    threshold,
                 }
             }
+            Some("verify") => AppArguments::Verify {
+                year: parse_year(&mut args)?,
+                day: args.opt_free_from_str()?,
+            },
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
-            },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
+                puzzle: parse_puzzle(&mut args)?,
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+            Some("examples") => AppArguments::Examples {
+                puzzle: parse_puzzle(&mut args)?,
             },
+            Some("scaffold") => {
+                let download = args.contains("--download");
+
+                AppArguments::Scaffold {
+                    puzzle: parse_puzzle(&mut args)?,
+                    download,
+                }
+            }
+            Some("solve") => {
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+                let json = args.contains("--json");
+                let watch = args.contains("--watch");
+                let input = parse_input(&mut args)?;
+                let puzzle = parse_puzzle(&mut args)?;
+
+                if watch && (submit.is_some() || dhat || json) {
+                    return Err(
+                        "`--watch` can not be combined with `--submit`, `--dhat` or `--json`."
+                            .into(),
+                    );
+                }
+
+                AppArguments::Solve {
+                    puzzle,
+                    input,
+                    release,
+                    submit,
+                    dhat,
+                    json,
+                    watch,
+                }
+            }
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -100,29 +238,67 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day, download } => {
-                scaffold::handle(day);
+            AppArguments::All {
+                year,
+                input,
+                release,
+            } => all::handle(year, registry(), &input, release),
+            AppArguments::Time {
+                year,
+                input,
+                day,
+                all,
+                store,
+                budget,
+                compare,
+                threshold,
+            } => time::handle(
+                year,
+                day,
+                registry(),
+                &input,
+                all,
+                store,
+                budget,
+                compare,
+                threshold,
+            ),
+            AppArguments::Verify { year, day } => verify::handle(year, day, registry()),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Examples { puzzle } => examples::handle(puzzle),
+            AppArguments::Scaffold { puzzle, download } => {
+                scaffold::handle(puzzle);
                 if download {
-                    download::handle(day);
+                    download::handle(puzzle);
+                    examples::handle(puzzle);
                 }
             }
             AppArguments::Solve {
-                day,
+                puzzle,
+                input,
                 release,
                 dhat,
+                json,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                watch: false,
+            } => solve::handle(puzzle, &input, release, dhat, json, submit),
+            AppArguments::Solve {
+                puzzle,
+                input,
+                release,
+                watch: true,
+                ..
+            } => watch::handle(puzzle, release, &input),
             #[cfg(feature = "today")]
             AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day);
-                        download::handle(day);
-                        read::handle(day)
+                match Year::today().zip(Day::today()) {
+                    Some((year, day)) => {
+                        let puzzle = PuzzleId::new(year, day);
+                        scaffold::handle(puzzle);
+                        download::handle(puzzle);
+                        examples::handle(puzzle);
+                        read::handle(puzzle)
                     }
                     None => {
                         eprintln!(
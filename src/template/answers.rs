@@ -0,0 +1,278 @@
+//! Known-good answers, used to detect regressions when refactoring solutions.
+
+use std::{collections::HashMap, fmt::Display, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, PuzzleId, Year, ANSI_BOLD, ANSI_RESET};
+
+static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// Represents the accepted answers for a single puzzle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Answer {
+    pub puzzle: PuzzleId,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Represents the accepted answers for a set of puzzles.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+/// Result of comparing an answer to the accepted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        expected: String,
+    },
+    /// No accepted answer has been recorded yet.
+    Unknown,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns empty answers.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(ANSWERS_FILE_PATH)
+            .map_err(|x| x.to_string())
+            .and_then(Answers::try_from)
+            .unwrap_or_default()
+    }
+
+    pub fn get(&self, puzzle: PuzzleId, part: u8) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.puzzle == puzzle)?;
+
+        match part {
+            1 => answer.part_1.as_deref(),
+            2 => answer.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Record the accepted answer for a part, overwriting a previously recorded answer.
+    pub fn set(&mut self, puzzle: PuzzleId, part: u8, value: &str) {
+        let index = match self.data.iter().position(|a| a.puzzle == puzzle) {
+            Some(index) => index,
+            None => {
+                self.data.push(Answer {
+                    puzzle,
+                    part_1: None,
+                    part_2: None,
+                });
+                self.data.sort_unstable_by_key(|a| a.puzzle);
+                self.data.iter().position(|a| a.puzzle == puzzle).unwrap()
+            }
+        };
+
+        match part {
+            1 => self.data[index].part_1 = Some(value.into()),
+            2 => self.data[index].part_2 = Some(value.into()),
+            _ => {}
+        }
+    }
+
+    /// Compare an answer to the accepted answer for a part.
+    pub fn verify(&self, puzzle: PuzzleId, part: u8, answer: Option<&str>) -> Verdict {
+        match self.get(puzzle, part) {
+            None => Verdict::Unknown,
+            Some(expected) if answer == Some(expected) => Verdict::Correct,
+            Some(expected) => Verdict::Incorrect {
+                expected: expected.into(),
+            },
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => f.write_str(" ✔"),
+            Verdict::Incorrect { expected } => {
+                write!(f, " ✖ expected {ANSI_BOLD}{expected}{ANSI_RESET}")
+            }
+            Verdict::Unknown => Ok(()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.puzzle.year.to_string()),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::String(value.puzzle.day.to_string()),
+        );
+
+        map.insert(
+            "part_1".into(),
+            value
+                .part_1
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "part_2".into(),
+            value
+                .part_2
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected answer.year to be a Year struct.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        Ok(Answer {
+            puzzle: PuzzleId::new(year, day),
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Verdict};
+    use crate::puzzle;
+
+    #[test]
+    fn records_answers() {
+        let mut answers = Answers::default();
+        answers.set(puzzle!(2023, 2), 1, "8");
+        answers.set(puzzle!(2023, 1), 2, "281");
+        answers.set(puzzle!(2023, 1), 1, "142");
+
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.data[0].puzzle, puzzle!(2023, 1));
+        assert_eq!(answers.get(puzzle!(2023, 1), 1), Some("142"));
+        assert_eq!(answers.get(puzzle!(2023, 1), 2), Some("281"));
+        assert_eq!(answers.get(puzzle!(2023, 2), 2), None);
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let mut answers = Answers::default();
+        answers.set(puzzle!(2023, 1), 1, "142");
+
+        assert_eq!(
+            answers.verify(puzzle!(2023, 1), 1, Some("142")),
+            Verdict::Correct
+        );
+        assert_eq!(
+            answers.verify(puzzle!(2023, 1), 1, Some("143")),
+            Verdict::Incorrect {
+                expected: "142".into()
+            }
+        );
+        assert_eq!(
+            answers.verify(puzzle!(2023, 1), 1, None),
+            Verdict::Incorrect {
+                expected: "142".into()
+            }
+        );
+        assert_eq!(
+            answers.verify(puzzle!(2023, 1), 2, Some("281")),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut answers = Answers::default();
+        answers.set(puzzle!(2023, 1), 1, "142");
+
+        let json = tinyjson::JsonValue::from(answers.clone())
+            .stringify()
+            .unwrap();
+        let parsed = Answers::try_from(json).unwrap();
+        assert_eq!(parsed.data, answers.data);
+    }
+}
@@ -0,0 +1,273 @@
+//! Converts the subset of HTML used by Advent of Code puzzle descriptions to markdown.
+
+enum Token<'a> {
+    Text(&'a str),
+    Open { name: String, attrs: &'a str },
+    Close(String),
+}
+
+/// Converts the `<article>` elements of a puzzle page to markdown.
+///
+/// Code blocks are written as fenced blocks, emphasis as bold text and inline code as code spans.
+/// Emphasized code, which puzzles use to highlight answers, is written as ``**`42`**``.
+pub fn html_to_markdown(html: &str) -> String {
+    // NOTE: code spans can not contain markup, swap the order so that emphasis wraps the span.
+    let html = html
+        .replace("<code><em>", "<em><code>")
+        .replace("</em></code>", "</code></em>");
+
+    let mut out = String::new();
+    let mut in_article = false;
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+
+    for token in tokenize(&html) {
+        match token {
+            Token::Open { name, .. } if name == "article" => in_article = true,
+            Token::Close(name) if name == "article" => in_article = false,
+            _ if !in_article => {}
+            Token::Text(text) => {
+                // skip whitespace between block elements.
+                if in_pre || !text.trim().is_empty() || !text.contains('\n') {
+                    out.push_str(&decode_entities(text));
+                }
+            }
+            Token::Open { name, attrs } => match name.as_str() {
+                "h2" => out.push_str("## "),
+                "pre" => {
+                    in_pre = true;
+                    out.push_str("```\n");
+                }
+                "em" | "strong" if !in_pre => out.push_str("**"),
+                "code" if !in_pre => out.push('`'),
+                "li" => out.push_str("- "),
+                "a" => {
+                    links.push(attr(attrs, "href").unwrap_or_default().to_string());
+                    out.push('[');
+                }
+                _ => {}
+            },
+            Token::Close(name) => match name.as_str() {
+                "h2" | "p" | "ul" => out.push_str("\n\n"),
+                "pre" => {
+                    in_pre = false;
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                }
+                "em" | "strong" if !in_pre => out.push_str("**"),
+                "code" if !in_pre => out.push('`'),
+                "li" => out.push('\n'),
+                "a" => {
+                    let href = links.pop().unwrap_or_default();
+                    out.push_str(&format!("]({href})"));
+                }
+                _ => {}
+            },
+        }
+    }
+
+    // collapse runs of blank lines left by nested block elements.
+    let mut markdown = String::with_capacity(out.len());
+    for line in out.trim().lines() {
+        if line.trim().is_empty() && markdown.ends_with("\n\n") {
+            continue;
+        }
+        markdown.push_str(line.trim_end());
+        markdown.push('\n');
+    }
+
+    markdown
+}
+
+/// Extracts the plain text of the `<article>` elements of a page, or of the whole page if it has none.
+pub fn article_text(html: &str) -> String {
+    let tokens = tokenize(html);
+    let has_article = tokens
+        .iter()
+        .any(|t| matches!(t, Token::Open { name, .. } if name == "article"));
+
+    let mut text = String::new();
+    let mut in_article = false;
+
+    for token in tokens {
+        match token {
+            Token::Open { name, .. } if name == "article" => in_article = true,
+            Token::Close(name) if name == "article" => in_article = false,
+            Token::Text(s) if in_article || !has_article => {
+                text.push_str(&decode_entities(s));
+                text.push(' ');
+            }
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+            rest = &rest[start..];
+            continue;
+        }
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        let tag = rest[1..end].trim_end_matches('/');
+        rest = &rest[end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim().to_lowercase()));
+        } else if !tag.starts_with('!') && !tag.starts_with('?') {
+            let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            let name = name.to_lowercase();
+
+            // the contents of raw text elements are not markup, skip them.
+            if name == "script" || name == "style" {
+                let close = format!("</{name}");
+                rest = rest.find(&close).map_or("", |end| &rest[end..]);
+            }
+
+            tokens.push(Token::Open { name, attrs });
+        }
+    }
+
+    tokens
+}
+
+fn attr<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = attrs[start..].find('"')?;
+    Some(&attrs[start..start + end])
+}
+
+fn decode_entities(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                entity => {
+                    let code = entity.strip_prefix('#')?;
+                    let code = match code.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => code.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{article_text, decode_entities, html_to_markdown};
+
+    const PUZZLE: &str = r#"<!DOCTYPE html>
+<html><head><script>window.x = "<article>";</script></head><body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Something is <em>wrong</em> with <a href="/2023/events">global snow production</a>.</p>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8<em>vwx</em>
+a&lt;b
+</code></pre>
+<ul>
+<li>The first line is <code>12</code>.</li>
+<li>In total, this produces <code><em>142</em></code>.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>54927</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Your calculation isn't quite right.</p>
+</article>
+</main>
+</body></html>"#;
+
+    #[test]
+    fn converts_puzzle_to_markdown() {
+        assert_eq!(
+            html_to_markdown(PUZZLE),
+            "## --- Day 1: Trebuchet?! ---
+
+Something is **wrong** with [global snow production](/2023/events).
+
+For example:
+
+```
+1abc2
+pqr3stu8vwx
+a<b
+```
+
+- The first line is `12`.
+- In total, this produces **`142`**.
+
+## --- Part Two ---
+
+Your calculation isn't quite right.
+"
+        );
+    }
+
+    #[test]
+    fn extracts_article_text() {
+        assert_eq!(
+            article_text("<main><article><p>That's the <em>right</em> answer!\n  You are</p></article></main>"),
+            "That's the right answer! You are"
+        );
+        assert_eq!(article_text("<p>No article</p>"), "No article");
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("&lt;a&gt; &amp; &#39;b&#x27; &unknown; &"),
+            "<a> & 'b' &unknown; &"
+        );
+    }
+}
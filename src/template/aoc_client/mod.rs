@@ -0,0 +1,459 @@
+//! A minimal client for the Advent of Code website.
+
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::PuzzleId;
+
+mod markdown;
+
+pub use markdown::html_to_markdown;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+static USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    /// Neither `AOC_SESSION` nor the session file are set.
+    SessionNotFound(PathBuf),
+    /// The server responded with a status code outside of 2xx.
+    BadStatus(u16),
+    /// The request could not be sent, e.g. due to a network error.
+    Transport(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound(path) => write!(
+                f,
+                "no session cookie found. Set `AOC_SESSION` or write it to \"{}\".",
+                path.display()
+            ),
+            AocClientError::BadStatus(404) => {
+                write!(f, "puzzle not found, it might not be unlocked yet.")
+            }
+            AocClientError::BadStatus(400) => {
+                write!(
+                    f,
+                    "server rejected the request, the session cookie might have expired."
+                )
+            }
+            AocClientError::BadStatus(status) => {
+                write!(f, "server responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "could not reach server: {e}"),
+            AocClientError::IO(e) => write!(f, "could not access file: {e}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+/// The server's response to a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmitResponse {
+    Correct,
+    /// The answer is wrong. `wait` is the time until the next answer will be accepted.
+    Wrong {
+        wait: Option<Duration>,
+    },
+    TooHigh {
+        wait: Option<Duration>,
+    },
+    TooLow {
+        wait: Option<Duration>,
+    },
+    /// An answer was submitted too recently, no verdict was given.
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part is either locked or already completed.
+    WrongLevel,
+    /// A response that could not be classified, contains the message of the server.
+    Unknown(String),
+}
+
+impl SubmitResponse {
+    /// Classifies the response page of an answer submission.
+    pub fn from_html(html: &str) -> Self {
+        let message = markdown::article_text(html);
+
+        if message.contains("That's the right answer") {
+            SubmitResponse::Correct
+        } else if message.contains("You gave an answer too recently") {
+            // NOTE: fall back to a minute if the wait time can not be read.
+            SubmitResponse::RateLimited {
+                wait: parse_wait(&message).unwrap_or(Duration::from_secs(60)),
+            }
+        } else if message.contains("That's not the right answer") {
+            let wait = parse_wait(&message);
+
+            if message.contains("your answer is too high") {
+                SubmitResponse::TooHigh { wait }
+            } else if message.contains("your answer is too low") {
+                SubmitResponse::TooLow { wait }
+            } else {
+                SubmitResponse::Wrong { wait }
+            }
+        } else if message.contains("You don't seem to be solving the right level") {
+            SubmitResponse::WrongLevel
+        } else {
+            SubmitResponse::Unknown(message)
+        }
+    }
+}
+
+impl Display for SubmitResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format_wait = |wait: &Option<Duration>| match wait {
+            Some(wait) => format!(" Wait {}s before submitting again.", wait.as_secs()),
+            None => String::new(),
+        };
+
+        match self {
+            SubmitResponse::Correct => write!(f, "That's the right answer! ⭐️"),
+            SubmitResponse::Wrong { wait } => {
+                write!(f, "That's not the right answer.{}", format_wait(wait))
+            }
+            SubmitResponse::TooHigh { wait } => {
+                write!(
+                    f,
+                    "That's not the right answer, it is too high.{}",
+                    format_wait(wait)
+                )
+            }
+            SubmitResponse::TooLow { wait } => {
+                write!(
+                    f,
+                    "That's not the right answer, it is too low.{}",
+                    format_wait(wait)
+                )
+            }
+            SubmitResponse::RateLimited { wait } => write!(
+                f,
+                "You gave an answer too recently. Wait {}s before submitting again.",
+                wait.as_secs()
+            ),
+            SubmitResponse::WrongLevel => {
+                write!(f, "This part is either locked or was already completed.")
+            }
+            SubmitResponse::Unknown(message) => write!(f, "Unexpected response: {message}"),
+        }
+    }
+}
+
+/// Reads wait times like "You have 1m 5s left to wait" or "please wait one minute".
+fn parse_wait(message: &str) -> Option<Duration> {
+    if let Some(start) = message.find("You have ") {
+        let rest = &message[start + "You have ".len()..];
+        let end = rest.find(" left to wait")?;
+
+        return rest[..end]
+            .split_whitespace()
+            .map(|token| {
+                let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+                let value: u64 = value.parse().ok()?;
+                match unit {
+                    "h" => Some(value * 3600),
+                    "m" => Some(value * 60),
+                    "s" => Some(value),
+                    _ => None,
+                }
+            })
+            .sum::<Option<u64>>()
+            .map(Duration::from_secs);
+    }
+
+    let start = message.find("wait ")?;
+    let mut tokens = message[start + "wait ".len()..].split_whitespace();
+
+    let value = match tokens.next()? {
+        "one" => 1,
+        "five" => 5,
+        "ten" => 10,
+        value => value.parse().ok()?,
+    };
+
+    match tokens.next()?.trim_end_matches(['.', ',']) {
+        "second" | "seconds" => Some(Duration::from_secs(value)),
+        "minute" | "minutes" => Some(Duration::from_secs(value * 60)),
+        _ => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Creates a client from the environment:
+    ///  1. the session cookie is read from `AOC_SESSION`, falling back to the file `~/.adventofcode.session`.
+    ///  2. the base url is read from `AOC_BASE_URL`, falling back to [`DEFAULT_BASE_URL`].
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let session = match env::var("AOC_SESSION") {
+            Ok(session) if !session.trim().is_empty() => session,
+            _ => {
+                let path = session_file_path();
+                fs::read_to_string(&path)
+                    .ok()
+                    .filter(|session| !session.trim().is_empty())
+                    .ok_or(AocClientError::SessionNotFound(path))?
+            }
+        };
+
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        Ok(Self::new(&base_url, &session))
+    }
+
+    /// Fetches the personal puzzle input.
+    pub fn input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let url = format!("{}/input", self.puzzle_url(puzzle));
+        Ok(self.get(&url)?.into_string()?)
+    }
+
+    /// Fetches the puzzle description and converts it to markdown.
+    /// The description contains part two if part one was solved.
+    pub fn puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let html = self.get(&self.puzzle_url(puzzle))?.into_string()?;
+        Ok(html_to_markdown(&html))
+    }
+
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmitResponse, AocClientError> {
+        let url = format!("{}/answer", self.puzzle_url(puzzle));
+        let part = part.to_string();
+
+        let html = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part), ("answer", answer)])?
+            .into_string()?;
+
+        Ok(SubmitResponse::from_html(&html))
+    }
+
+    fn get(&self, url: &str) -> Result<ureq::Response, AocClientError> {
+        Ok(self.agent.get(url).set("Cookie", &self.cookie()).call()?)
+    }
+
+    fn puzzle_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn session_file_path() -> PathBuf {
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .unwrap_or_default();
+    Path::new(&home).join(".adventofcode.session")
+}
+
+/* -------------------------------------------------------------------------- */
+
+pub fn get_input_path(puzzle: PuzzleId) -> String {
+    format!("data/inputs/{}/{}.txt", puzzle.year, puzzle.day)
+}
+
+pub fn get_puzzle_path(puzzle: PuzzleId) -> String {
+    format!("data/puzzles/{}/{}.md", puzzle.year, puzzle.day)
+}
+
+/// Writes a file, creating its parent directories if necessary.
+pub fn write_file(path: &str, contents: &str) -> Result<(), AocClientError> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_wait, AocClient, AocClientError, SubmitResponse};
+    use crate::puzzle;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+        time::Duration,
+    };
+
+    /// Serves a single request with the given status and body, returns the base url
+    /// and a handle that resolves to the raw request.
+    fn serve_once(status: u16, body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = value.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn fetches_input() {
+        let (base_url, handle) = serve_once(200, "1abc2\npqr3stu8vwx\n");
+        let client = AocClient::new(&base_url, "abc\n");
+
+        let input = client.input(puzzle!(2023, 1)).unwrap();
+        assert_eq!(input, "1abc2\npqr3stu8vwx\n");
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=abc\r\n"));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, handle) = serve_once(
+            200,
+            "<main><article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article></main>",
+        );
+        let client = AocClient::new(&base_url, "abc");
+
+        let response = client.submit(puzzle!(2023, 8), 2, "42").unwrap();
+        assert_eq!(response, SubmitResponse::Correct);
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/8/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (base_url, handle) = serve_once(404, "Not found");
+        let client = AocClient::new(&base_url, "abc");
+
+        let result = client.input(puzzle!(2023, 25));
+        assert!(matches!(result, Err(AocClientError::BadStatus(404))));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn classifies_responses() {
+        assert_eq!(
+            SubmitResponse::from_html("<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>"),
+            SubmitResponse::TooHigh { wait: Some(Duration::from_secs(60)) }
+        );
+        assert_eq!(
+            SubmitResponse::from_html("<article><p>That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.</p></article>"),
+            SubmitResponse::TooLow { wait: Some(Duration::from_secs(300)) }
+        );
+        assert_eq!(
+            SubmitResponse::from_html("<article><p>That's not the right answer.  If you're stuck, there are some general tips on the <a href=\"/2023/about\">about page</a>.</p></article>"),
+            SubmitResponse::Wrong { wait: None }
+        );
+        assert_eq!(
+            SubmitResponse::from_html("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>"),
+            SubmitResponse::RateLimited { wait: Duration::from_secs(65) }
+        );
+        assert_eq!(
+            SubmitResponse::from_html("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"),
+            SubmitResponse::WrongLevel
+        );
+        assert!(matches!(
+            SubmitResponse::from_html("<p>Maintenance</p>"),
+            SubmitResponse::Unknown(_)
+        ));
+    }
+
+    #[test]
+    fn parses_wait_times() {
+        assert_eq!(
+            parse_wait("You have 37s left to wait."),
+            Some(Duration::from_secs(37))
+        );
+        assert_eq!(
+            parse_wait("please wait one minute before"),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            parse_wait("please wait 10 minutes."),
+            Some(Duration::from_secs(600))
+        );
+        assert_eq!(parse_wait("no wait time"), None);
+    }
+}
@@ -1,5 +1,14 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{
+    all_puzzles, input::InputSource, registry::Solution, run_multi::run_multi, Year,
+};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(year: Year, registry: &[Solution], input: &InputSource, is_release: bool) {
+    run_multi(
+        &all_puzzles(year).collect(),
+        registry,
+        input,
+        is_release,
+        false,
+        None,
+    );
 }
@@ -1,14 +1,32 @@
-use crate::template::{aoc_cli, Day};
+use crate::template::aoc_client::{
+    get_input_path, get_puzzle_path, write_file, AocClient, AocClientError,
+};
+use crate::template::PuzzleId;
 use std::process;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+pub fn handle(puzzle: PuzzleId) {
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = download(&client, puzzle) {
+        eprintln!("failed to download puzzle: {e}");
         process::exit(1);
     };
 }
+
+fn download(client: &AocClient, puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let input_path = get_input_path(puzzle);
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    write_file(&input_path, &client.input(puzzle)?)?;
+    write_file(&puzzle_path, &client.puzzle(puzzle)?)?;
+
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
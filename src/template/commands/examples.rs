@@ -0,0 +1,68 @@
+use std::{fs, process};
+
+use crate::template::aoc_client::{get_puzzle_path, write_file};
+use crate::template::examples::{extract, prefill_tests};
+use crate::template::PuzzleId;
+
+pub fn handle(puzzle: PuzzleId) {
+    let PuzzleId { year, day } = puzzle;
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    let Ok(description) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Could not read puzzle description \"{puzzle_path}\". Type `cargo download {day} --year {year}` to download it."
+        );
+        process::exit(1);
+    };
+
+    let examples = extract(&description);
+
+    if examples.inputs.is_empty() {
+        println!("No examples found in \"{puzzle_path}\".");
+        return;
+    }
+
+    let example_paths: Vec<String> = (1..=examples.inputs.len())
+        .map(|k| format!("data/examples/{year}/{day}-{k}.txt"))
+        .collect();
+
+    for (path, input) in example_paths.iter().zip(&examples.inputs) {
+        // never overwrite examples that were edited by hand.
+        if fs::read_to_string(path).is_ok_and(|s| !s.trim().is_empty()) {
+            println!("Skipped existing example file \"{path}\"");
+            continue;
+        }
+
+        match write_file(path, input) {
+            Ok(()) => println!("Created example file \"{path}\""),
+            Err(e) => {
+                eprintln!("Failed to write example file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    for (part, expected) in examples.expected.iter().enumerate() {
+        if let Some(expected) = expected {
+            println!(
+                "Part {}: expected `{}` for \"{}\"",
+                part + 1,
+                expected.answer,
+                example_paths[expected.example]
+            );
+        }
+    }
+
+    let module_path = format!("src/bin/{}.rs", puzzle.bin_name());
+
+    if let Ok(module) = fs::read_to_string(&module_path) {
+        let filled = prefill_tests(&module, &examples);
+
+        if filled != module {
+            match fs::write(&module_path, filled) {
+                Ok(()) => println!("Filled in example tests in \"{module_path}\""),
+                Err(e) => eprintln!("Failed to update module file: {e}"),
+            }
+        }
+    }
+}
@@ -1,15 +1,31 @@
 use std::process;
 
-use crate::template::{aoc_cli, Day};
+use crate::template::aoc_client::{get_puzzle_path, write_file, AocClient};
+use crate::template::PuzzleId;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+pub fn handle(puzzle: PuzzleId) {
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    println!("Fetching puzzle for day {}, {}...", puzzle.day, puzzle.year);
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    let description = match client.puzzle(puzzle) {
+        Ok(description) => description,
+        Err(e) => {
+            eprintln!("failed to fetch puzzle: {e}");
+            process::exit(1);
+        }
     };
+
+    // keep the local copy up-to-date, e.g. after part two was unlocked.
+    if let Err(e) = write_file(&get_puzzle_path(puzzle), &description) {
+        eprintln!("failed to write puzzle: {e}");
+    }
+
+    println!("\n{description}");
 }
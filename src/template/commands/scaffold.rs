@@ -1,26 +1,41 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::PuzzleId;
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+    create_parent_dir(path)?;
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    create_parent_dir(path)?;
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+fn create_parent_dir(path: &str) -> Result<(), std::io::Error> {
+    match Path::new(path).parent() {
+        Some(dir) => fs::create_dir_all(dir),
+        None => Ok(()),
+    }
+}
+
+pub fn handle(puzzle: PuzzleId) {
+    let PuzzleId { year, day } = puzzle;
+    let input_path = format!("data/inputs/{year}/{day}.txt");
+    let example_path = format!("data/examples/{year}/{day}.txt");
+    let module_path = format!("src/bin/{}.rs", puzzle.bin_name());
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -32,6 +47,7 @@ pub fn handle(day: Day) {
 
     match file.write_all(
         MODULE_TEMPLATE
+            .replace("%YEAR_NUMBER%", &year.into_inner().to_string())
             .replace("%DAY_NUMBER%", &day.into_inner().to_string())
             .as_bytes(),
     ) {
@@ -65,5 +81,5 @@ pub fn handle(day: Day) {
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
 }
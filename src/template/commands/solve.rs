@@ -1,9 +1,16 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{input::InputSource, PuzzleId};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(
+    puzzle: PuzzleId,
+    input: &InputSource,
+    release: bool,
+    dhat: bool,
+    json: bool,
+    submit_part: Option<u8>,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.bin_name()];
 
     if dhat {
         cmd_args.extend([
@@ -18,6 +25,12 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd_args.push("--".to_string());
 
+    if json {
+        cmd_args.push("--format=jsonl".to_string());
+    }
+
+    cmd_args.extend(input.to_args());
+
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
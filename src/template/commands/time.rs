@@ -1,29 +1,66 @@
 use std::collections::HashSet;
+use std::process::{self, Command};
 
+use crate::template::input::InputSource;
+use crate::template::registry::Solution;
+use crate::template::report::part_name;
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::timings::{Comparison, Timings};
+use crate::template::{
+    all_puzzles, readme_benchmarks, unix_now, Day, PuzzleId, Year, ANSI_BOLD, ANSI_RESET,
+};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+/// Default threshold in percent for `--compare` to consider a slower part a regression.
+pub const DEFAULT_REGRESSION_THRESHOLD: f64 = 10.0;
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    registry: &[Solution],
+    input: &InputSource,
+    run_all: bool,
+    store: bool,
+    budget: Option<u64>,
+    compare: bool,
+    threshold: Option<f64>,
+) {
     let stored_timings = Timings::read_from_file();
 
-    let days_to_run = day.map_or_else(
+    let puzzles_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                all_puzzles(year).collect()
+            } else if compare {
+                // when comparing, bench the days that have a baseline.
+                stored_timings
+                    .data
+                    .iter()
+                    .map(|t| t.puzzle)
+                    .filter(|puzzle| puzzle.year == year)
+                    .collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                all_puzzles(year)
+                    .filter(|puzzle| !stored_timings.is_day_complete(*puzzle))
                     .collect()
             }
         },
-        |day| HashSet::from([day]),
+        |day| HashSet::from([PuzzleId::new(year, day)]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let mut timings = run_multi(&puzzles_to_run, registry, input, true, true, budget).unwrap();
+
+    let regressions = if compare {
+        let threshold = threshold.unwrap_or(DEFAULT_REGRESSION_THRESHOLD);
+        print_comparisons(&stored_timings.compare(&timings), threshold)
+    } else {
+        0
+    };
 
     if store {
+        timings.record_history(current_commit().as_deref(), unix_now());
+
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
@@ -37,4 +74,62 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if regressions > 0 {
+        eprintln!("{regressions} part(s) regressed by more than the threshold.");
+        process::exit(1);
+    }
+}
+
+/// Prints the comparisons to the baseline and returns the number of regressions.
+fn print_comparisons(comparisons: &[Comparison], threshold: f64) -> usize {
+    println!();
+    println!("{ANSI_BOLD}Compared to baseline{ANSI_RESET}");
+    println!("-------------");
+
+    if comparisons.is_empty() {
+        println!("No baseline found. Store one with `cargo time --store`.");
+        return 0;
+    }
+
+    let mut regressions = 0;
+
+    for comparison in comparisons {
+        let is_regression = comparison.is_regression(threshold / 100.0);
+
+        let verdict = if is_regression {
+            regressions += 1;
+            "✖ regression"
+        } else if !comparison.is_significant {
+            "~ no significant change"
+        } else if comparison.change < 0.0 {
+            "✔ faster"
+        } else {
+            "slower"
+        };
+
+        println!(
+            "{} {}: {:.1?} → {:.1?} ({:+.1}%) {verdict}",
+            comparison.puzzle,
+            part_name(comparison.part).to_lowercase(),
+            comparison.baseline,
+            comparison.current,
+            comparison.change * 100.0
+        );
+    }
+
+    regressions
+}
+
+/// Short hash of the commit that is checked out, if this is a git repository.
+fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
 }
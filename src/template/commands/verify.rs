@@ -0,0 +1,62 @@
+use std::{collections::HashSet, process};
+
+use crate::template::answers::{Answers, Verdict};
+use crate::template::input::InputSource;
+use crate::template::registry::Solution;
+use crate::template::run_multi::run_puzzles;
+use crate::template::{Day, PuzzleId, Year, ANSI_BOLD, ANSI_RESET};
+
+pub fn handle(year: Year, day: Option<Day>, registry: &[Solution]) {
+    let answers = Answers::read_from_file();
+
+    // only run puzzles that have at least one recorded answer.
+    let puzzles_to_run: HashSet<PuzzleId> = answers
+        .data
+        .iter()
+        .map(|answer| answer.puzzle)
+        .filter(|puzzle| puzzle.year == year && day.is_none_or(|day| puzzle.day == day))
+        .collect();
+
+    if puzzles_to_run.is_empty() {
+        eprintln!("No recorded answers found in \"data/answers.json\". Answers are recorded when submitting a correct answer.");
+        process::exit(1);
+    }
+
+    let results = run_puzzles(
+        &puzzles_to_run,
+        registry,
+        &InputSource::Puzzle,
+        true,
+        false,
+        None,
+    );
+
+    let mut correct = 0;
+    let mut failed: Vec<String> = vec![];
+
+    for (puzzle, reports) in &results {
+        for part in 1..=2 {
+            let answer = reports
+                .iter()
+                .find(|report| report.part == part)
+                .and_then(|report| report.answer.as_deref());
+
+            match answers.verify(*puzzle, part, answer) {
+                Verdict::Correct => correct += 1,
+                Verdict::Incorrect { .. } => failed.push(format!("{puzzle} part {part}")),
+                Verdict::Unknown => {}
+            }
+        }
+    }
+
+    println!();
+    println!(
+        "{ANSI_BOLD}Verified:{ANSI_RESET} {correct} correct, {} incorrect.",
+        failed.len()
+    );
+
+    if !failed.is_empty() {
+        eprintln!("Incorrect answers: {}", failed.join(", "));
+        process::exit(1);
+    }
+}
@@ -0,0 +1,211 @@
+use std::{
+    fs,
+    path::PathBuf,
+    process::{self, Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::answers::Answers;
+use crate::template::input::InputSource;
+use crate::template::report::{PartReport, PARSE_PART};
+use crate::template::run_multi::child_commands;
+use crate::template::{PuzzleId, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// How often watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Runs tests and the solution of a puzzle whenever its source, input or examples change.
+pub fn handle(puzzle: PuzzleId, is_release: bool, input: &InputSource) {
+    if *input == InputSource::Stdin {
+        eprintln!("`--watch` can not be combined with reading the input from stdin.");
+        process::exit(1);
+    }
+
+    let mut previous: Option<Vec<PartReport>> = None;
+    let mut files = watched_files(puzzle, input);
+
+    loop {
+        println!("{ANSI_BOLD}Tests{ANSI_RESET}");
+        println!("-------------");
+        run_tests(puzzle, is_release);
+
+        println!();
+        println!(
+            "{ANSI_BOLD}Day {} ({}){ANSI_RESET}",
+            puzzle.day, puzzle.year
+        );
+        println!("-------------");
+
+        let answers = Answers::read_from_file();
+        let reports =
+            child_commands::run_solution(puzzle, &answers, input, false, is_release, None)
+                .unwrap_or_default();
+
+        if let Some(previous) = &previous {
+            println!();
+            println!("{ANSI_BOLD}Changes{ANSI_RESET}");
+            println!("-------------");
+            for line in diff_answers(previous, &reports) {
+                println!("{line}");
+            }
+        }
+
+        previous = Some(reports);
+
+        println!();
+        println!("{ANSI_ITALIC}Watching for changes, press Ctrl+C to exit.{ANSI_RESET}");
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let next = watched_files(puzzle, input);
+            if next != files {
+                files = next;
+                break;
+            }
+        }
+
+        println!();
+    }
+}
+
+fn run_tests(puzzle: PuzzleId, is_release: bool) {
+    let bin_name = puzzle.bin_name();
+    let mut args = vec!["test", "--quiet", "--bin", &bin_name];
+
+    if is_release {
+        args.push("--release");
+    }
+
+    let status = Command::new("cargo")
+        .args(&args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status();
+
+    match status {
+        Ok(status) if status.success() => println!("Tests: ✔"),
+        _ => println!("Tests: ✖"),
+    }
+}
+
+/// The source, input and example files of a puzzle, together with their modification time.
+fn watched_files(puzzle: PuzzleId, input: &InputSource) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let PuzzleId { year, day } = puzzle;
+
+    let mut paths = vec![
+        PathBuf::from(format!("src/bin/{}.rs", puzzle.bin_name())),
+        PathBuf::from(format!("data/inputs/{year}/{day}.txt")),
+    ];
+
+    if let InputSource::File(path) = input {
+        paths.push(path.clone());
+    }
+
+    // matches both `01.txt` and `01-2.txt`.
+    if let Ok(entries) = fs::read_dir(format!("data/examples/{year}")) {
+        let mut examples: Vec<PathBuf> = entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| {
+                        name.starts_with(&day.to_string()) && name.ends_with(".txt")
+                    })
+            })
+            .collect();
+        examples.sort_unstable();
+        paths.extend(examples);
+    }
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Compares the answers of two runs part by part.
+fn diff_answers(previous: &[PartReport], current: &[PartReport]) -> Vec<String> {
+    let answer = |reports: &[PartReport], part: u8| {
+        reports
+            .iter()
+            .find(|report| report.part == part)
+            .and_then(|report| report.answer.clone())
+    };
+
+    // NOTE: `parse` functions have no answer to compare.
+    let mut parts: Vec<u8> = previous
+        .iter()
+        .chain(current)
+        .map(|r| r.part)
+        .filter(|part| *part != PARSE_PART)
+        .collect();
+    parts.sort_unstable();
+    parts.dedup();
+
+    parts
+        .into_iter()
+        .map(|part| {
+            let before = answer(previous, part);
+            let after = answer(current, part);
+
+            if before == after {
+                format!("Part {part}: unchanged")
+            } else {
+                format!(
+                    "Part {part}: {} → {ANSI_BOLD}{}{ANSI_RESET}",
+                    before.as_deref().unwrap_or("✖"),
+                    after.as_deref().unwrap_or("✖")
+                )
+            }
+        })
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::diff_answers;
+    use crate::template::{
+        report::{PartReport, PartStatus},
+        ANSI_BOLD, ANSI_RESET,
+    };
+    use std::time::Duration;
+
+    fn report(part: u8, answer: Option<&str>) -> PartReport {
+        PartReport {
+            part,
+            status: match answer {
+                Some(_) => PartStatus::Solved,
+                None => PartStatus::Unimplemented,
+            },
+            answer: answer.map(String::from),
+            error: None,
+            duration: Duration::from_nanos(1),
+            stats: None,
+        }
+    }
+
+    #[test]
+    fn diffs_answers() {
+        let diff = diff_answers(
+            &[report(1, Some("42")), report(2, None)],
+            &[report(1, Some("42")), report(2, Some("7"))],
+        );
+        assert_eq!(
+            diff,
+            vec![
+                "Part 1: unchanged".to_string(),
+                format!("Part 2: ✖ → {ANSI_BOLD}7{ANSI_RESET}")
+            ]
+        );
+    }
+
+    #[test]
+    fn diffs_missing_reports() {
+        let diff = diff_answers(&[report(1, Some("42"))], &[]);
+        assert_eq!(diff, vec![format!("Part 1: 42 → {ANSI_BOLD}✖{ANSI_RESET}")]);
+    }
+}
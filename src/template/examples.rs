@@ -0,0 +1,236 @@
+//! Extracts example inputs and their expected answers from puzzle descriptions.
+
+/// The expected answer of a part for one of the example inputs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expected {
+    /// Index into [`PuzzleExamples::inputs`].
+    pub example: usize,
+    pub answer: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PuzzleExamples {
+    /// Example inputs in order of appearance, without duplicates.
+    pub inputs: Vec<String>,
+    /// Expected answers of part one and part two, if they could be detected.
+    pub expected: [Option<Expected>; 2],
+}
+
+/// Parses a puzzle description that was written by [`crate::template::aoc_client::html_to_markdown`].
+///
+/// A code block is considered an example input if it is the first code block of the puzzle or if the
+/// paragraph leading up to it mentions an example. The last emphasized code span of a part, e.g.
+/// ``**`142`**``, is taken to be the expected answer for the most recent example input.
+pub fn extract(markdown: &str) -> PuzzleExamples {
+    let mut examples = PuzzleExamples::default();
+
+    let mut part = 0;
+    let mut paragraph = String::new();
+    let mut code_block: Option<String> = None;
+    let mut seen_code_block = false;
+    let mut last_example: Option<usize> = None;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            match code_block.take() {
+                None => code_block = Some(String::new()),
+                Some(block) => {
+                    let is_example =
+                        !seen_code_block || paragraph.to_lowercase().contains("example");
+
+                    if is_example && !block.trim().is_empty() {
+                        let index = examples
+                            .inputs
+                            .iter()
+                            .position(|input| *input == block)
+                            .unwrap_or_else(|| {
+                                examples.inputs.push(block);
+                                examples.inputs.len() - 1
+                            });
+                        last_example = Some(index);
+                    }
+
+                    seen_code_block = true;
+                    paragraph.clear();
+                }
+            }
+            continue;
+        }
+
+        if let Some(block) = code_block.as_mut() {
+            block.push_str(line);
+            block.push('\n');
+            continue;
+        }
+
+        if line.starts_with("## --- Part Two") {
+            part = 1;
+        }
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        paragraph.clear();
+        paragraph.push_str(line);
+
+        if let (Some(example), Some(answer)) = (last_example, emphasized_code(line).last()) {
+            examples.expected[part] = Some(Expected {
+                example,
+                answer: (*answer).to_string(),
+            });
+        }
+    }
+
+    examples
+}
+
+/// Fills in the example tests of a module that were generated from `template.txt`.
+/// Tests that were already changed are left untouched.
+pub fn prefill_tests(module: &str, examples: &PuzzleExamples) -> String {
+    let mut module = module.to_string();
+
+    for (func, expected) in ["part_one", "part_two"].iter().zip(&examples.expected) {
+        let Some(Expected { example, answer }) = expected else {
+            continue;
+        };
+
+        let generated = format!(
+            "let result = {func}(&advent_of_code::template::read_file(\"examples\", PUZZLE));\n        assert_eq!(result, None);"
+        );
+
+        let answer = if answer.parse::<i128>().is_ok() {
+            answer.clone()
+        } else {
+            format!("String::from({answer:?})")
+        };
+
+        let filled = format!(
+            "let result = {func}(&advent_of_code::template::read_file_part(\"examples\", PUZZLE, {}));\n        assert_eq!(result, Some({answer}));",
+            example + 1
+        );
+
+        module = module.replacen(&generated, &filled, 1);
+    }
+
+    module
+}
+
+/// Finds code spans that are emphasized, like ``**`42`**``.
+fn emphasized_code(line: &str) -> Vec<&str> {
+    line.split("**`")
+        .skip(1)
+        .filter_map(|s| s.split_once("`**").map(|(code, _)| code))
+        .collect()
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract, prefill_tests, Expected, PuzzleExamples};
+
+    const PUZZLE: &str = "## --- Day 1: Trebuchet?! ---
+
+The newly-improved calibration document consists of lines of text.
+
+For example:
+
+```
+1abc2
+treb7uchet
+```
+
+In this example, the calibration values are `12` and `77`. Adding these together produces **`142`**.
+
+## --- Part Two ---
+
+It looks like some of the digits are actually spelled out with letters.
+
+Equipped with this new information, you now need to find the real values. For example:
+
+```
+two1nine
+7pqrstsixteen
+```
+
+Adding these together produces **`281`**.
+
+What is the sum of all of the calibration values?
+";
+
+    #[test]
+    fn extracts_examples_and_answers() {
+        let examples = extract(PUZZLE);
+        assert_eq!(
+            examples.inputs,
+            vec!["1abc2\ntreb7uchet\n", "two1nine\n7pqrstsixteen\n"]
+        );
+        assert_eq!(
+            examples.expected,
+            [
+                Some(Expected {
+                    example: 0,
+                    answer: "142".into()
+                }),
+                Some(Expected {
+                    example: 1,
+                    answer: "281".into()
+                })
+            ]
+        );
+    }
+
+    #[test]
+    fn reuses_examples_between_parts() {
+        let examples = extract(
+            "For example:\n\n```\n1 2\n```\n\nAfter one step:\n\n```\n3 4\n```\n\nIt has **`3`** values, the result is **`7`**.\n\n## --- Part Two ---\n\nNow, the result is **`9`**.\n",
+        );
+        assert_eq!(examples.inputs, vec!["1 2\n"]);
+        assert_eq!(
+            examples.expected[0],
+            Some(Expected {
+                example: 0,
+                answer: "7".into()
+            })
+        );
+        assert_eq!(
+            examples.expected[1],
+            Some(Expected {
+                example: 0,
+                answer: "9".into()
+            })
+        );
+    }
+
+    #[test]
+    fn handles_missing_examples() {
+        let examples = extract("## --- Day 1 ---\n\nNo code, but **`1`**.\n");
+        assert!(examples.inputs.is_empty());
+        assert_eq!(examples.expected, [None, None]);
+    }
+
+    #[test]
+    fn prefills_generated_tests() {
+        let module = include_str!("../template.txt");
+        let examples = PuzzleExamples {
+            inputs: vec!["1\n".into(), "2\n".into()],
+            expected: [
+                None,
+                Some(Expected {
+                    example: 1,
+                    answer: "281".into(),
+                }),
+            ],
+        };
+
+        let filled = prefill_tests(module, &examples);
+        assert!(filled.contains(
+            "let result = part_one(&advent_of_code::template::read_file(\"examples\", PUZZLE));\n        assert_eq!(result, None);"
+        ));
+        assert!(filled.contains(
+            "let result = part_two(&advent_of_code::template::read_file_part(\"examples\", PUZZLE, 2));\n        assert_eq!(result, Some(281));"
+        ));
+
+        // tests that were changed are not touched.
+        assert_eq!(prefill_tests(&filled, &examples), filled);
+    }
+}
@@ -0,0 +1,131 @@
+//! Selects the input that a solution is run against.
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use crate::template::{read_file_checked, read_file_part_checked, PuzzleId};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// The puzzle input in `data/inputs`.
+    #[default]
+    Puzzle,
+    /// An example in `data/examples`, e.g. `01.txt` or `01-2.txt` if a number is passed.
+    Example(Option<u8>),
+    /// A file at a custom path, e.g. the input of a teammate.
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parses `--input <path>` or `--example [k]` from the arguments of a solution binary.
+    /// A path of `-` selects stdin.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let value = |flag: &str| args.iter().position(|x| x == flag).map(|i| args.get(i + 1));
+
+        match (value("--input"), value("--example")) {
+            (Some(_), Some(_)) => Err("`--input` and `--example` can not be combined.".into()),
+            (Some(None), None) => Err("expected a path after `--input`.".into()),
+            (Some(Some(path)), None) => Ok(Self::from_path(path)),
+            // NOTE: the number is optional, so `--example --time` is an example without one.
+            (None, Some(None)) => Ok(Self::Example(None)),
+            (None, Some(Some(k))) if k.starts_with("--") => Ok(Self::Example(None)),
+            (None, Some(Some(k))) => k
+                .parse()
+                .map(|k| Self::Example(Some(k)))
+                .map_err(|_| "expected an example number after `--example`.".into()),
+            (None, None) => Ok(Self::Puzzle),
+        }
+    }
+
+    /// The input at `path`, or stdin if `path` is `-`.
+    pub fn from_path(path: &str) -> Self {
+        match path {
+            "-" => Self::Stdin,
+            path => Self::File(path.into()),
+        }
+    }
+
+    /// The arguments that select this input when passed to a solution binary, see [`InputSource::from_args`].
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Self::Puzzle => vec![],
+            Self::Example(None) => vec!["--example".into()],
+            Self::Example(Some(k)) => vec!["--example".into(), k.to_string()],
+            Self::File(path) => vec!["--input".into(), path.to_string_lossy().into()],
+            Self::Stdin => vec!["--input".into(), "-".into()],
+        }
+    }
+
+    pub fn read(&self, puzzle: PuzzleId) -> io::Result<String> {
+        match self {
+            Self::Puzzle => read_file_checked("inputs", puzzle),
+            Self::Example(None) => read_file_checked("examples", puzzle),
+            Self::Example(Some(k)) => read_file_part_checked("examples", puzzle, *k),
+            Self::File(path) => fs::read_to_string(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Puzzle => write!(f, "puzzle input"),
+            Self::Example(None) => write!(f, "example"),
+            Self::Example(Some(k)) => write!(f, "example {k}"),
+            Self::File(path) => write!(f, "\"{}\"", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::InputSource;
+
+    fn parse(args: &str) -> Result<InputSource, String> {
+        let args: Vec<String> = args.split_whitespace().map(String::from).collect();
+        InputSource::from_args(&args)
+    }
+
+    #[test]
+    fn parses_input_arguments() {
+        assert_eq!(parse("--time"), Ok(InputSource::Puzzle));
+        assert_eq!(parse("--example"), Ok(InputSource::Example(None)));
+        assert_eq!(parse("--example --time"), Ok(InputSource::Example(None)));
+        assert_eq!(
+            parse("--example 2 --time"),
+            Ok(InputSource::Example(Some(2)))
+        );
+        assert_eq!(parse("--input -"), Ok(InputSource::Stdin));
+        assert_eq!(
+            parse("--time --input data/other.txt"),
+            Ok(InputSource::File("data/other.txt".into()))
+        );
+        assert!(parse("--example foo").is_err());
+        assert!(parse("--example 300").is_err());
+        assert!(parse("--input").is_err());
+        assert!(parse("--input a.txt --example").is_err());
+    }
+
+    #[test]
+    fn roundtrips_arguments() {
+        for input in [
+            InputSource::Puzzle,
+            InputSource::Example(None),
+            InputSource::Example(Some(3)),
+            InputSource::File("in.txt".into()),
+            InputSource::Stdin,
+        ] {
+            assert_eq!(InputSource::from_args(&input.to_args()), Ok(input));
+        }
+    }
+}
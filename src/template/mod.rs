@@ -1,68 +1,156 @@
-use std::{env, fs};
+use std::{
+    env, fs, io,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
-pub mod aoc_cli;
+pub mod answers;
+pub mod aoc_client;
 pub mod commands;
+pub mod input;
+pub mod registry;
 pub mod runner;
 
 pub use day::*;
+pub use puzzle::*;
+pub use year::*;
 
 mod day;
+mod examples;
+mod puzzle;
 mod readme_benchmarks;
+pub mod report;
 mod run_multi;
+mod stats;
+mod submissions;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Current time in seconds since the unix epoch.
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
+    let f = read_file_checked(folder, puzzle);
     f.expect("could not open input file")
 }
 
+/// Helper function that reads a text file to a string, returning an error if it can't be read.
+pub fn read_file_checked(folder: &str, puzzle: PuzzleId) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    let filepath = cwd
+        .join("data")
+        .join(folder)
+        .join(puzzle.year.to_string())
+        .join(format!("{}.txt", puzzle.day));
+    fs::read_to_string(filepath)
+}
+
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: u8) -> String {
+    let f = read_file_part_checked(folder, puzzle, part);
+    f.expect("could not open input file")
+}
+
+/// Helper function that reads a text file with a part suffix to string, returning an error if it can't be read.
+pub fn read_file_part_checked(folder: &str, puzzle: PuzzleId, part: u8) -> io::Result<String> {
+    let cwd = env::current_dir()?;
     let filepath = cwd
         .join("data")
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+        .join(puzzle.year.to_string())
+        .join(format!("{}-{part}.txt", puzzle.day));
+    fs::read_to_string(filepath)
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constant `PUZZLE` and sets up the input and runner for each part.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The first two parameters are the year and the day of the puzzle.
+/// The optional, third parameter (1 or 2) allows you to only run a single part of the solution.
+/// Parts may return an `Option` or a `Result`, see [`runner::PartResult`].
+/// The input is read from `data/inputs`, unless another one is selected with `--input` or `--example`.
+///
+/// Alternatively, a `parse = <function>` parameter declares a function that turns the input into a
+/// `Result<T, E>`. It runs once and is timed separately, both parts take a `&T` instead of the input.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1] [part_two, 2]);
+    };
+    ($year:expr, $day:expr, 1) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($year:expr, $day:expr, 2) => {
+        $crate::solution!(@impl $year, $day, [part_two, 2]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($year:expr, $day:expr, parse = $parse:expr) => {
+        $crate::solution!(@parsed $year, $day, $parse, [part_one, 1] [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+    (@puzzle $year:expr, $day:expr) => {
+        /// The current puzzle.
+        pub const PUZZLE: $crate::template::PuzzleId = $crate::puzzle!($year, $day);
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
+    };
+
+    (@impl $year:expr, $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@puzzle $year, $day);
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let input = read_input(PUZZLE);
+            $( run_part($func, &input, PUZZLE, $part); )*
+        }
+
+        /// Entry point for the in-process runner, see [`$crate::template::registry`].
+        #[doc(hidden)]
+        pub fn __run_in_process(
+            input: &str,
+            bench_budget: Option<std::time::Duration>,
+        ) -> Vec<$crate::template::report::PartReport> {
+            use $crate::template::runner::*;
+            vec![$( run_part_report($func, input, PUZZLE, $part, bench_budget), )*]
+        }
+    };
+
+    (@parsed $year:expr, $day:expr, $parse:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@puzzle $year, $day);
+
+        fn main() {
+            use $crate::template::runner::*;
+            let input = read_input(PUZZLE);
+            match run_parse($parse, &input) {
+                Some(parsed) => { $( run_part($func, &parsed, PUZZLE, $part); )* }
+                None => { $( skip_part($part); )* }
+            }
+        }
+
+        /// Entry point for the in-process runner, see [`$crate::template::registry`].
+        #[doc(hidden)]
+        pub fn __run_in_process(
+            input: &str,
+            bench_budget: Option<std::time::Duration>,
+        ) -> Vec<$crate::template::report::PartReport> {
+            use $crate::template::runner::*;
+            let (parsed, report) = run_parse_report($parse, input, bench_budget);
+            let mut reports = vec![report];
+            match parsed {
+                Some(parsed) => reports.extend([$( run_part_report($func, &parsed, PUZZLE, $part, bench_budget), )*]),
+                None => reports.extend([$( skip_part_report($part), )*]),
+            }
+            reports
         }
     };
 }
@@ -0,0 +1,54 @@
+use std::fmt::Display;
+
+use crate::template::{Day, Year};
+
+/// Identifies a single puzzle of advent of code by its year and day.
+///
+/// # Display
+/// This value displays as `<year>/<day>`.
+///
+/// ```
+/// # use advent_of_code::template::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2023).unwrap(), Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2023/08");
+/// assert_eq!(puzzle.bin_name(), "y2023_d08");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Name of the binary that contains the solution for this puzzle, e.g. `y2023_d01`.
+    pub fn bin_name(&self) -> String {
+        format!("y{}_d{}", self.year, self.day)
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An iterator that yields every puzzle of a year of advent from the 1st to the 25th.
+pub fn all_puzzles(year: Year) -> impl Iterator<Item = PuzzleId> {
+    super::all_days().map(move |day| PuzzleId::new(year, day))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`PuzzleId`] value in a const context.
+#[macro_export]
+macro_rules! puzzle {
+    ($year:expr, $day:expr) => {
+        $crate::template::PuzzleId::new($crate::year!($year), $crate::day!($day))
+    };
+}
@@ -2,14 +2,19 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
+use itertools::Itertools;
+
+use crate::template::report::PartStatus;
 use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::PuzzleId;
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
 #[derive(Debug)]
 pub enum Error {
+    #[allow(dead_code)]
     Parser(String),
+    #[allow(dead_code)]
     IO(io::Error),
 }
 
@@ -25,8 +30,8 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{}.rs", puzzle.bin_name())
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -54,23 +59,27 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
 fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
-
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    let mut lines: Vec<String> = vec![MARKER.into(), header];
+
+    // NOTE: `group_by` only groups consecutive elements, timings are sorted by puzzle when merged.
+    for (year, timings) in &timings.data.into_iter().group_by(|t| t.puzzle.year) {
+        lines.push(String::new());
+        lines.push(format!("{prefix}# {year}"));
+        lines.push(String::new());
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+
+        for timing in timings {
+            let path = get_path_for_bin(timing.puzzle);
+            lines.push(format!(
+                "| [Day {}]({}) | `{}` | `{}` | `{}` |",
+                timing.puzzle.day.into_inner(),
+                path,
+                timing.parse.as_deref().unwrap_or("-"),
+                format_part(timing.part_1, timing.part_1_status),
+                format_part(timing.part_2, timing.part_2_status)
+            ));
+        }
     }
 
     lines.push(String::new());
@@ -80,6 +89,15 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
+/// The execution time of a part, or why there is none.
+fn format_part(time: Option<String>, status: PartStatus) -> String {
+    match (time, status) {
+        (Some(time), _) => time,
+        (None, PartStatus::Failed) => "✖".into(),
+        (None, _) => "-".into(),
+    }
+}
+
 fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
     let positions = locate_table(s)?;
     let table = construct_table("##", timings, total_millis);
@@ -99,30 +117,51 @@ pub fn update(timings: Timings) -> Result<(), Error> {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        puzzle, template::report::PartStatus, template::timings::Timing, template::timings::Timings,
+    };
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2023, 1),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: PartStatus::Solved,
+                    part_2_status: PartStatus::Solved,
                     total_nanos: 3e+10,
                 },
                 Timing {
-                    day: day!(2),
+                    puzzle: puzzle!(2023, 2),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: PartStatus::Solved,
+                    part_2_status: PartStatus::Solved,
                     total_nanos: 7e+10,
                 },
                 Timing {
-                    day: day!(4),
+                    puzzle: puzzle!(2023, 4),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: PartStatus::Solved,
+                    part_2_status: PartStatus::Solved,
                     total_nanos: 9e+10,
                 },
             ],
+            history: vec![],
         }
     }
 
@@ -166,11 +205,13 @@ mod tests {
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "### 2023",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/y2023_d01.rs) | `-` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/y2023_d02.rs) | `-` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/y2023_d04.rs) | `-` | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -179,4 +220,39 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+    #[test]
+    fn groups_benchmarks_by_year() {
+        let mut timings = get_mock_timings();
+        timings.data.push(Timing {
+            puzzle: puzzle!(2024, 1),
+            parse: Some("5µs".into()),
+            parse_stats: None,
+            part_1: Some("1ms".into()),
+            part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            part_1_status: PartStatus::Solved,
+            part_2_status: PartStatus::Unimplemented,
+            total_nanos: 1e+6,
+        });
+        timings.data.push(Timing {
+            puzzle: puzzle!(2024, 2),
+            parse: None,
+            parse_stats: None,
+            part_1: None,
+            part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            part_1_status: PartStatus::Failed,
+            part_2_status: PartStatus::Unimplemented,
+            total_nanos: 0.0,
+        });
+
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, timings, 191.0).unwrap();
+        assert_eq!(s.matches("### 2023").count(), 1);
+        assert_eq!(s.matches("### 2024").count(), 1);
+        assert!(s.contains("| [Day 1](./src/bin/y2024_d01.rs) | `5µs` | `1ms` | `-` |"));
+        assert!(s.contains("| [Day 2](./src/bin/y2024_d02.rs) | `-` | `✖` | `-` |"));
+    }
 }
@@ -0,0 +1,16 @@
+//! Solutions that are compiled into the main binary, so they can be run without spawning `cargo`.
+//! The registry is generated by `build.rs` when the `in-process` feature is enabled.
+
+use std::time::Duration;
+
+use crate::template::{report::PartReport, PuzzleId};
+
+/// Entry point of a solution, generated by the [`solution!`](crate::solution) macro.
+/// Runs all parts against an input and benches them if a budget is passed.
+pub type RunFn = fn(&str, Option<Duration>) -> Vec<PartReport>;
+
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    pub run: RunFn,
+}
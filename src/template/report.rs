@@ -0,0 +1,248 @@
+//! Machine-readable results that solution binaries emit in `--format=jsonl` mode.
+
+use std::{collections::HashMap, fmt::Display, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::stats::BenchStats;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    /// The part returned an answer.
+    Solved,
+    /// The part returned `None`, which means that it is not implemented yet.
+    Unimplemented,
+    /// The part returned an error.
+    Failed,
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartStatus::Solved => f.write_str("solved"),
+            PartStatus::Unimplemented => f.write_str("unimplemented"),
+            PartStatus::Failed => f.write_str("failed"),
+        }
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(PartStatus::Solved),
+            "unimplemented" => Ok(PartStatus::Unimplemented),
+            "failed" => Ok(PartStatus::Failed),
+            s => Err(format!("Unknown part status `{s}`.")),
+        }
+    }
+}
+
+/// The part number of the reports of `parse` functions, see [`crate::solution`].
+pub const PARSE_PART: u8 = 0;
+
+/// The name of a part in output, e.g. `Part 1` or `Parse`.
+pub fn part_name(part: u8) -> String {
+    match part {
+        PARSE_PART => "Parse".into(),
+        part => format!("Part {part}"),
+    }
+}
+
+/// The result of running a single part of a solution, or of its `parse` function.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartReport {
+    pub part: u8,
+    pub status: PartStatus,
+    pub answer: Option<String>,
+    /// The error message of a failed part.
+    pub error: Option<String>,
+    /// Execution time of the part. When benched, this is the median of all samples.
+    pub duration: Duration,
+    pub stats: Option<BenchStats>,
+}
+
+impl PartReport {
+    /// The answer of the part, or its error message if it failed.
+    pub fn outcome(&self) -> Result<Option<&str>, &str> {
+        match self.status {
+            PartStatus::Failed => Err(self.error.as_deref().unwrap_or_default()),
+            _ => Ok(self.answer.as_deref()),
+        }
+    }
+
+    /// Serializes the report to a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        // NOTE: `stringify` does not emit line breaks, line breaks in answers are escaped.
+        JsonValue::from(self).stringify().unwrap()
+    }
+
+    /// Parses a line that was written by [`PartReport::to_json_line`].
+    pub fn from_json_line(line: &str) -> Result<Self, String> {
+        let json = JsonValue::from_str(line).or(Err("not valid JSON."))?;
+        PartReport::try_from(&json)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartReport> for JsonValue {
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("status".into(), JsonValue::String(value.status.to_string()));
+
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "error".into(),
+            value
+                .error
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "duration_nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(value.stats.as_ref().map_or(1, |s| s.samples) as f64),
+        );
+
+        map.insert(
+            "stats".into(),
+            value
+                .stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u8)
+            .ok_or("Expected report.part to be a number.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected report.status to be a string.")?
+            .parse()?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected report.answer to be null or string.")?;
+
+        let error = json
+            .get("error")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected report.error to be null or string.")?;
+
+        let duration_nanos = json
+            .get("duration_nanos")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected report.duration_nanos to be a number.")?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        Ok(PartReport {
+            part,
+            status,
+            answer: answer.cloned(),
+            error: error.cloned(),
+            duration: Duration::from_nanos(*duration_nanos as u64),
+            stats,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PartReport, PartStatus};
+    use crate::template::stats::BenchStats;
+    use std::time::Duration;
+
+    #[test]
+    fn roundtrips_reports() {
+        let report = PartReport {
+            part: 2,
+            status: PartStatus::Solved,
+            answer: Some("line (1)\nline @ 2 samples)".into()),
+            error: None,
+            duration: Duration::from_nanos(74),
+            stats: BenchStats::from_samples(&[Duration::from_nanos(74); 3]),
+        };
+
+        let line = report.to_json_line();
+        assert!(!line.contains('\n'));
+        assert_eq!(PartReport::from_json_line(&line), Ok(report));
+    }
+
+    #[test]
+    fn roundtrips_unsolved_reports() {
+        let report = PartReport {
+            part: 1,
+            status: PartStatus::Unimplemented,
+            answer: None,
+            error: None,
+            duration: Duration::from_nanos(10),
+            stats: None,
+        };
+
+        assert_eq!(
+            PartReport::from_json_line(&report.to_json_line()),
+            Ok(report)
+        );
+
+        let report = PartReport {
+            part: 2,
+            status: PartStatus::Failed,
+            answer: None,
+            error: Some("expecting an integer at 1:3, found 'x'".into()),
+            duration: Duration::from_nanos(10),
+            stats: None,
+        };
+
+        let parsed = PartReport::from_json_line(&report.to_json_line()).unwrap();
+        assert_eq!(
+            parsed.outcome(),
+            Err("expecting an integer at 1:3, found 'x'")
+        );
+        assert_eq!(parsed, report);
+    }
+
+    #[test]
+    fn errors_for_other_output() {
+        assert!(PartReport::from_json_line("Part 1: 42 (1ms)").is_err());
+        assert!(PartReport::from_json_line(r#"{ "part": 1 }"#).is_err());
+    }
+}
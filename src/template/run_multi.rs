@@ -1,54 +1,130 @@
 use std::{collections::HashSet, io};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-
-use super::{
-    all_days,
-    timings::{Timing, Timings},
+use crate::template::{
+    answers::Answers,
+    input::InputSource,
+    registry::Solution,
+    report::{PartReport, PartStatus},
+    PuzzleId, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+use super::timings::{Timing, Timings};
+
+/// Runs a set of solutions. Solutions present in the `registry` are run in-process,
+/// all other solutions are run by invoking their binary.
+pub fn run_multi(
+    puzzles_to_run: &HashSet<PuzzleId>,
+    registry: &[Solution],
+    input: &InputSource,
+    is_release: bool,
+    is_timed: bool,
+    bench_budget: Option<u64>,
+) -> Option<Timings> {
+    let timings: Vec<Timing> = run_puzzles(
+        puzzles_to_run,
+        registry,
+        input,
+        is_release,
+        is_timed,
+        bench_budget,
+    )
+    .iter()
+    .filter(|(_, reports)| !reports.is_empty())
+    .map(|(puzzle, reports)| child_commands::parse_exec_time(reports, *puzzle))
+    .collect();
+
+    let failed: Vec<String> = timings
+        .iter()
+        .flat_map(|timing| {
+            [(1, timing.part_1_status), (2, timing.part_2_status)]
+                .into_iter()
+                .filter(|(_, status)| *status == PartStatus::Failed)
+                .map(move |(part, _)| format!("{} part {part}", timing.puzzle))
+        })
+        .collect();
+
+    if !failed.is_empty() {
+        eprintln!("\nFailed parts: {}", failed.join(", "));
+    }
+
+    if is_timed {
+        let timings = Timings {
+            data: timings,
+            history: vec![],
+        };
+        let total_millis = timings.total_millis();
+        println!(
+            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+        );
+        Some(timings)
+    } else {
+        None
+    }
+}
+
+/// Runs a set of solutions in order and returns the reports of each puzzle.
+/// Puzzles that could not be run have no reports.
+pub fn run_puzzles(
+    puzzles_to_run: &HashSet<PuzzleId>,
+    registry: &[Solution],
+    input: &InputSource,
+    is_release: bool,
+    is_timed: bool,
+    bench_budget: Option<u64>,
+) -> Vec<(PuzzleId, Vec<PartReport>)> {
+    let answers = Answers::read_from_file();
 
     let mut need_space = false;
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
+    // NOTE: use non-duplicate, sorted puzzle values.
+    let mut puzzles: Vec<PuzzleId> = puzzles_to_run.iter().copied().collect();
+    puzzles.sort_unstable();
+
+    puzzles
+        .into_iter()
+        .map(|puzzle| {
             if need_space {
                 println!();
             }
             need_space = true;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
-
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+            println!(
+                "{ANSI_BOLD}Day {} ({}){ANSI_RESET}",
+                puzzle.day, puzzle.year
+            );
+            println!("-------------");
+
+            // NOTE: in-process runs verify their answers, which only makes sense for puzzle inputs.
+            let solution = registry
+                .iter()
+                .find(|solution| solution.puzzle == puzzle && *input == InputSource::Puzzle);
+
+            let output = match solution {
+                Some(solution) => in_process::run_solution(solution, is_timed, bench_budget),
+                None => child_commands::run_solution(
+                    puzzle,
+                    &answers,
+                    input,
+                    is_timed,
+                    is_release,
+                    bench_budget,
+                )
+                .unwrap(),
+            };
 
             if output.is_empty() {
                 println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
             }
-        });
 
-    if is_timed {
-        let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
-        println!(
-            "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
-        );
-        Some(timings)
-    } else {
-        None
-    }
+            (puzzle, output)
+        })
+        .collect()
 }
 
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
+    #[allow(dead_code)]
     IO(io::Error),
 }
 
@@ -59,15 +135,54 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    format!("./src/bin/{}.rs", puzzle.bin_name())
+}
+
+/// Solutions that were compiled into the main binary can be run without spawning `cargo`.
+pub mod in_process {
+    use std::{panic, time::Duration};
+
+    use crate::template::{
+        read_file_checked, registry::Solution, report::PartReport, runner::DEFAULT_BENCH_BUDGET,
+    };
+
+    /// Run a registered solution against its input. Mirrors [`super::child_commands::run_solution`],
+    /// a missing input file or a panicking solution yield no reports.
+    pub fn run_solution(
+        solution: &Solution,
+        is_timed: bool,
+        bench_budget: Option<u64>,
+    ) -> Vec<PartReport> {
+        let input = match read_file_checked("inputs", solution.puzzle) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("could not open input file: {e}");
+                return vec![];
+            }
+        };
+
+        let bench_budget = if is_timed {
+            Some(bench_budget.map_or(DEFAULT_BENCH_BUDGET, Duration::from_millis))
+        } else {
+            None
+        };
+
+        panic::catch_unwind(|| (solution.run)(&input, bench_budget)).unwrap_or_default()
+    }
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as parsing their output.
 pub mod child_commands {
     use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use crate::template::{
+        answers::{Answers, Verdict},
+        input::InputSource,
+        report::{PartReport, PartStatus, PARSE_PART},
+        runner::print_report,
+        PuzzleId,
+    };
     use std::{
         io::{BufRead, BufReader},
         path::Path,
@@ -76,27 +191,45 @@ pub mod child_commands {
     };
 
     /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        answers: &Answers,
+        input: &InputSource,
+        is_timed: bool,
+        is_release: bool,
+        bench_budget: Option<u64>,
+    ) -> Result<Vec<PartReport>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !Path::new(&get_path_for_bin(puzzle)).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = puzzle.bin_name();
+        let budget_str = bench_budget.map(|x| x.to_string());
+        let input_args = input.to_args();
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
 
         if is_release {
             args.push("--release");
         }
 
+        // request machine-readable output from the child.
+        args.push("--");
+        args.push("--format=jsonl");
+        args.extend(input_args.iter().map(String::as_str));
+
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
+
+            if let Some(budget_str) = &budget_str {
+                args.push("--budget");
+                args.push(budget_str);
+            }
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr while collecting reports from stdout.
 
         let mut cmd = Command::new("cargo")
             .args(&args)
@@ -117,8 +250,21 @@ pub mod child_commands {
 
         for line in stdout.lines() {
             let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+
+            // lines that are not reports stem from the solution itself, e.g. debug output.
+            match PartReport::from_json_line(&line) {
+                Ok(report) => {
+                    let verdict = match input {
+                        InputSource::Puzzle => {
+                            answers.verify(puzzle, report.part, report.answer.as_deref())
+                        }
+                        _ => Verdict::Unknown,
+                    };
+                    print_report(&report, &verdict);
+                    output.push(report);
+                }
+                Err(_) => println!("{line}"),
+            }
         }
 
         thread.join().unwrap();
@@ -127,67 +273,51 @@ pub mod child_commands {
         Ok(output)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
+    pub fn parse_exec_time(reports: &[PartReport], puzzle: PuzzleId) -> super::Timing {
         let mut timings = super::Timing {
-            day,
+            puzzle,
+            parse: None,
+            parse_stats: None,
             part_1: None,
             part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            part_1_status: PartStatus::Unimplemented,
+            part_2_status: PartStatus::Unimplemented,
             total_nanos: 0_f64,
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        for report in reports {
+            match report.part {
+                1 => timings.part_1_status = report.status,
+                2 => timings.part_2_status = report.status,
+                _ => {}
+            }
+        }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+        reports
+            .iter()
+            .filter(|report| report.status == PartStatus::Solved)
+            .for_each(|report| {
+                let timing_str = format!("{:.1?}", report.duration);
+
+                if report.part == PARSE_PART {
+                    timings.parse = Some(timing_str);
+                    timings.parse_stats.clone_from(&report.stats);
+                } else if report.part == 1 {
+                    timings.part_1 = Some(timing_str);
+                    timings.part_1_stats.clone_from(&report.stats);
+                } else if report.part == 2 {
+                    timings.part_2 = Some(timing_str);
+                    timings.part_2_stats.clone_from(&report.stats);
                 }
 
-                timings.total_nanos += nanos;
+                timings.total_nanos += report.duration.as_nanos() as f64;
             });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
-
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
     #[cfg(feature = "test_lib")]
     macro_rules! assert_approx_eq {
@@ -205,52 +335,118 @@ pub mod child_commands {
     #[cfg(feature = "test_lib")]
     mod tests {
         use super::parse_exec_time;
+        use std::time::Duration;
 
-        use crate::day;
+        use crate::{
+            puzzle,
+            template::report::{PartReport, PartStatus},
+        };
+
+        fn parse_reports(lines: &[&str]) -> Vec<PartReport> {
+            lines
+                .iter()
+                .filter_map(|l| PartReport::from_json_line(l).ok())
+                .collect()
+        }
 
         #[test]
         fn parses_execution_times() {
             let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+                &parse_reports(&[
+                    r#"{"part":1,"status":"solved","answer":"0","error":null,"duration_nanos":74,"samples":100000,"stats":null}"#,
+                    r#"{"part":2,"status":"solved","answer":"10","error":null,"duration_nanos":74130000,"samples":99999,"stats":null}"#,
+                    "",
+                ]),
+                puzzle!(2023, 1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
         }
 
         #[test]
         fn parses_with_patterns_in_input() {
             let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
+                &parse_reports(&[
+                    r#"{"part":1,"status":"solved","answer":"@ @ @ ( ) ms (2s @ 5 samples)","error":null,"duration_nanos":2000000000,"samples":5,"stats":null}"#,
+                    "Part 2: 10s (100ms @ 1 samples)",
+                    r#"{"part":2,"status":"solved","answer":"10s","error":null,"duration_nanos":100000000,"samples":1,"stats":null}"#,
+                ]),
+                puzzle!(2023, 1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert_eq!(res.part_2.unwrap(), "100.0ms");
+        }
+
+        #[test]
+        fn parses_statistics() {
+            let res = parse_exec_time(
+                &parse_reports(&[
+                    r#"{"part":1,"status":"solved","answer":"42","error":null,"duration_nanos":74,"samples":1000,"stats":{"median_nanos":74,"mean_nanos":75,"stddev_nanos":2,"p95_nanos":80,"min_nanos":70,"max_nanos":90,"samples":1000,"outliers":3}}"#,
+                    r#"{"part":2,"status":"solved","answer":"(1)","error":null,"duration_nanos":2000000,"samples":1,"stats":null}"#,
+                ]),
+                puzzle!(2023, 1),
+            );
+            assert_approx_eq!(res.total_nanos, 2000074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "2.0ms");
+            assert!(res.part_2_stats.is_none());
+
+            let stats = res.part_1_stats.unwrap();
+            assert_eq!(stats.median, Duration::from_nanos(74));
+            assert_eq!(stats.mean, Duration::from_nanos(75));
+            assert_eq!(stats.stddev, Duration::from_nanos(2));
+            assert_eq!(stats.p95, Duration::from_nanos(80));
+            assert_eq!(stats.min, Duration::from_nanos(70));
+            assert_eq!(stats.max, Duration::from_nanos(90));
+            assert_eq!(stats.samples, 1000);
+            assert_eq!(stats.outliers, 3);
         }
 
         #[test]
         fn parses_missing_parts() {
             let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
+                &parse_reports(&[
+                    r#"{"part":1,"status":"unimplemented","answer":null,"error":null,"duration_nanos":10,"samples":1,"stats":null}"#,
+                    r#"{"part":2,"status":"unimplemented","answer":null,"error":null,"duration_nanos":10,"samples":1,"stats":null}"#,
+                    "",
+                ]),
+                puzzle!(2023, 1),
             );
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn parses_parse_times() {
+            let res = parse_exec_time(
+                &parse_reports(&[
+                    r#"{"part":0,"status":"solved","answer":null,"error":null,"duration_nanos":1000,"samples":1,"stats":null}"#,
+                    r#"{"part":1,"status":"solved","answer":"5","error":null,"duration_nanos":74,"samples":1,"stats":null}"#,
+                    r#"{"part":2,"status":"solved","answer":"7","error":null,"duration_nanos":26,"samples":1,"stats":null}"#,
+                ]),
+                puzzle!(2023, 22),
+            );
+            assert_approx_eq!(res.total_nanos, 1100_f64);
+            assert_eq!(res.parse.unwrap(), "1.0µs");
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+        }
+
+        #[test]
+        fn keeps_statuses_of_failed_parts() {
+            let res = parse_exec_time(
+                &parse_reports(&[
+                    r#"{"part":1,"status":"solved","answer":"42","error":null,"duration_nanos":10,"samples":1,"stats":null}"#,
+                    r#"{"part":2,"status":"failed","answer":null,"error":"expecting an integer at 1:1, found 'x'","duration_nanos":10,"samples":1,"stats":null}"#,
+                ]),
+                puzzle!(2023, 1),
+            );
+            assert_approx_eq!(res.total_nanos, 10_f64);
+            assert_eq!(res.part_1_status, PartStatus::Solved);
+            assert_eq!(res.part_2_status, PartStatus::Failed);
+            assert!(res.part_2.is_none());
+        }
     }
 }
@@ -2,34 +2,241 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+use crate::template::answers::{Answers, Verdict};
+use crate::template::aoc_client::{AocClient, AocClientError, SubmitResponse};
+use crate::template::input::InputSource;
+use crate::template::report::{part_name, PartReport, PartStatus, PARSE_PART};
+use crate::template::stats::BenchStats;
+use crate::template::submissions::Submissions;
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{unix_now, PuzzleId, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// Default time budget for benchmarking a single part.
+pub const DEFAULT_BENCH_BUDGET: Duration = Duration::from_secs(1);
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+/// The types that solution parts may return.
+///  1. `Option<T>`, where `None` means that the part is not implemented yet.
+///  2. `Result<T, E>`, where an error means that the part failed. Errors are formatted with `{:#}`,
+///     which includes the causes of `anyhow`-style error chains.
+pub trait PartResult {
+    /// The answer of the part, or an error message if it failed.
+    fn outcome(&self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> PartResult for Option<T> {
+    fn outcome(&self) -> Result<Option<String>, String> {
+        Ok(self.as_ref().map(ToString::to_string))
+    }
+}
+
+impl<T: Display, E: Display> PartResult for Result<T, E> {
+    fn outcome(&self) -> Result<Option<String>, String> {
+        match self {
+            Ok(answer) => Ok(Some(answer.to_string())),
+            Err(err) => Err(format!("{err:#}")),
+        }
+    }
+}
+
+/// Read the input that was selected with `--input <path>` or `--example [k]`, by default the puzzle input.
+/// Exits if the input can not be read.
+pub fn read_input(puzzle: PuzzleId) -> String {
+    let args: Vec<String> = env::args().collect();
+
+    let source = InputSource::from_args(&args).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+
+    source.read(puzzle).unwrap_or_else(|e| {
+        eprintln!("could not read {source}: {e}");
+        process::exit(1);
+    })
+}
+
+/// Whether the solution runs against its puzzle input. Answers for other inputs are neither verified nor submitted.
+fn is_puzzle_input() -> bool {
+    let args: Vec<String> = env::args().collect();
+    InputSource::from_args(&args) == Ok(InputSource::Puzzle)
+}
+
+pub fn run_part<I: Clone, R: PartResult>(
+    func: impl Fn(I) -> R,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+) {
+    let bench_budget = bench_budget();
+
+    if is_json_output() {
+        let report = measure_part(func, input, part, bench_budget, |_| {});
+        println!("{}", report.to_json_line());
+        return;
+    }
+
+    let report = run_part_report(func, input, puzzle, part, bench_budget);
+
+    if let Some(answer) = report.answer {
+        submit_result(answer, puzzle, part);
+    }
+}
+
+/// Run a solution part, print its result and return it as a report.
+/// The part is benched if a `bench_budget` is passed.
+/// The result is checked against the accepted answer in `data/answers.json`, if one was recorded.
+pub fn run_part_report<I: Clone, R: PartResult>(
+    func: impl Fn(I) -> R,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+    bench_budget: Option<Duration>,
+) -> PartReport {
+    let part_str = part_name(part);
+
+    let report = measure_part(func, input, part, bench_budget, |result| {
+        let outcome = result.outcome();
+        print_result(
+            outcome
+                .as_ref()
+                .map(Option::as_deref)
+                .map_err(String::as_str),
+            &part_str,
+            "",
+        );
+    });
+
+    let verdict = if is_puzzle_input() {
+        Answers::read_from_file().verify(puzzle, part, report.answer.as_deref())
+    } else {
+        Verdict::Unknown
+    };
+
+    print_report(&report, &verdict);
+    report
+}
+
+fn measure_part<I: Clone, R: PartResult>(
+    func: impl Fn(I) -> R,
+    input: I,
+    part: u8,
+    bench_budget: Option<Duration>,
+    hook: impl Fn(&R),
+) -> PartReport {
+    let (result, duration, stats) = run_timed(func, input, hook, bench_budget);
+
+    let (status, answer, error) = match result.outcome() {
+        Ok(Some(answer)) => (PartStatus::Solved, Some(answer), None),
+        Ok(None) => (PartStatus::Unimplemented, None, None),
+        Err(error) => (PartStatus::Failed, None, Some(error)),
+    };
+
+    PartReport {
+        part,
+        status,
+        answer,
+        error,
+        duration,
+        stats,
+    }
+}
+
+/// Run the `parse` function of a solution, which may be benched like a part.
+/// Returns the parsed input that is passed to the parts, or `None` if parsing failed.
+/// In that case, the parts are reported with [`skip_part`] instead of being run.
+pub fn run_parse<T, E: Display>(func: impl Fn(&str) -> Result<T, E>, input: &str) -> Option<T> {
+    let bench_budget = bench_budget();
+
+    if is_json_output() {
+        let (parsed, report) = measure_parse(func, input, bench_budget, |_| {});
+        println!("{}", report.to_json_line());
+        return parsed;
+    }
+
+    run_parse_report(func, input, bench_budget).0
+}
+
+/// Run the `parse` function of a solution and print its report, see [`run_parse`].
+pub fn run_parse_report<T, E: Display>(
+    func: impl Fn(&str) -> Result<T, E>,
+    input: &str,
+    bench_budget: Option<Duration>,
+) -> (Option<T>, PartReport) {
+    let part_str = part_name(PARSE_PART);
+
+    let (parsed, report) = measure_parse(func, input, bench_budget, |result| {
+        let error = result.as_ref().err().map(|err| format!("{err:#}"));
+        print_result(error.as_deref().map_or(Ok(Some("✔")), Err), &part_str, "");
+    });
+
+    print_report(&report, &Verdict::Unknown);
+    (parsed, report)
+}
+
+fn measure_parse<T, E: Display>(
+    func: impl Fn(&str) -> Result<T, E>,
+    input: &str,
+    bench_budget: Option<Duration>,
+    hook: impl Fn(&Result<T, E>),
+) -> (Option<T>, PartReport) {
+    let (result, duration, stats) = run_timed(func, input, hook, bench_budget);
+
+    let (status, parsed, error) = match result {
+        Ok(parsed) => (PartStatus::Solved, Some(parsed), None),
+        Err(err) => (PartStatus::Failed, None, Some(format!("{err:#}"))),
+    };
+
+    let report = PartReport {
+        part: PARSE_PART,
+        status,
+        answer: None,
+        error,
+        duration,
+        stats,
+    };
+
+    (parsed, report)
+}
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+/// Report a part that was not run because its input could not be parsed.
+pub fn skip_part(part: u8) {
+    if is_json_output() {
+        println!("{}", skipped_report(part).to_json_line());
+    } else {
+        skip_part_report(part);
+    }
+}
+
+/// Print and return the report of a part that was not run, see [`skip_part`].
+pub fn skip_part_report(part: u8) -> PartReport {
+    let report = skipped_report(part);
+    print_report(&report, &Verdict::Unknown);
+    report
+}
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+fn skipped_report(part: u8) -> PartReport {
+    PartReport {
+        part,
+        status: PartStatus::Failed,
+        answer: None,
+        error: Some("the input could not be parsed".into()),
+        duration: Duration::ZERO,
+        stats: None,
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part. The behavior differs depending on whether a `bench_budget` is passed:
+///  1. without it, the function is executed once.
+///  2. with it, the function is benched after a short warmup (approx. the budget of execution time or 10 samples,
+///     whatever take longer).
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    bench_budget: Option<Duration>,
+) -> (T, Duration, Option<BenchStats>) {
     let timer = Instant::now();
     let result = {
         let input = input.clone();
@@ -43,28 +250,35 @@ fn run_timed<I: Clone, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
-    };
-
-    (result, run.0, run.1)
+    match bench_budget {
+        Some(budget) => {
+            let stats = bench(func, input, &base_time, budget);
+            (result, stats.median, Some(stats))
+        }
+        None => (result, base_time, None),
+    }
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    budget: Duration,
+) -> BenchStats {
+    if !is_json_output() {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    // warm up caches and branch predictors, as long as this fits into a tenth of the budget.
+    let warmup_budget = budget / 10;
+    let warmup_timer = Instant::now();
+    while warmup_timer.elapsed() + *base_time < warmup_budget {
+        black_box(func(black_box(input.clone())));
+    }
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    let bench_iterations =
+        (budget.as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -76,35 +290,74 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    // NOTE: `timers` holds at least 10 samples.
+    BenchStats::from_samples(&timers).unwrap()
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
+/// Parse the `--time` flag and the `--budget <millis>` argument that controls how long each part is benched for.
+fn bench_budget() -> Option<Duration> {
+    let args: Vec<String> = env::args().collect();
+
+    if !args.iter().any(|x| x == "--time") {
+        return None;
+    }
+
+    let budget = args
         .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+        .position(|x| x == "--budget")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|x| x.parse().ok())
+        .map_or(DEFAULT_BENCH_BUDGET, Duration::from_millis);
+
+    Some(budget)
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+/// Whether results should be written as JSON lines instead of human-readable text.
+/// Enabled by passing `--format=jsonl` or `--json`.
+fn is_json_output() -> bool {
+    env::args().any(|x| x == "--format=jsonl" || x == "--json")
+}
+
+/// Print a report that was emitted by a solution binary in JSON output mode,
+/// followed by the verdict against the accepted answer.
+pub(crate) fn print_report(report: &PartReport, verdict: &Verdict) {
+    print_result(
+        parse_outcome(report.part, report.outcome()),
+        &part_name(report.part),
+        &format!(
+            "{}{verdict}",
+            format_duration(&report.duration, report.stats.as_ref())
+        ),
+    );
+}
+
+/// A parsed input is not an answer, so successful `parse` functions are marked as such.
+fn parse_outcome<'a>(
+    part: u8,
+    outcome: Result<Option<&'a str>, &'a str>,
+) -> Result<Option<&'a str>, &'a str> {
+    match (part, outcome) {
+        (PARSE_PART, Ok(_)) => Ok(Some("✔")),
+        (_, outcome) => outcome,
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn format_duration(duration: &Duration, stats: Option<&BenchStats>) -> String {
+    match stats {
+        None => format!(" ({duration:.1?})"),
+        Some(stats) => format!(
+            " ({duration:.1?} @ {} samples; mean {:.1?}, stddev {:.1?}, p95 {:.1?}, min {:.1?}, max {:.1?}, outliers {})",
+            stats.samples, stats.mean, stats.stddev, stats.p95, stats.min, stats.max, stats.outliers
+        ),
+    }
+}
+
+fn print_result(outcome: Result<Option<&str>, &str>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
+    match outcome {
+        Ok(Some(result)) => {
+            if result.contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
@@ -123,7 +376,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Ok(None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -131,17 +384,29 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        Err(error) => {
+            let str = format!("{part}: ✖ {ANSI_ITALIC}{error}{ANSI_RESET}");
+            if is_intermediate_result {
+                print!("{str}");
+            } else {
+                print!("\r");
+                println!("{str}{duration_str}");
+            }
+        }
     }
 }
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. a session cookie is configured.
+///  3. previous submissions in `data/submissions.json` do not rule out the answer.
+///
+/// Every submission is logged. If the answer is accepted, it is recorded in `data/answers.json`.
 fn submit_result<T: Display>(
     result: T,
-    day: Day,
+    puzzle: PuzzleId,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<SubmitResponse, AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -164,11 +429,55 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    if !is_puzzle_input() {
+        eprintln!("Not submitting `{result}`: it was not computed from the puzzle input.");
+        return None;
+    }
+
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let result = result.to_string();
+    let mut submissions = Submissions::read_from_file();
+
+    if let Err(rejection) = submissions.check(puzzle, part, &result, unix_now()) {
+        eprintln!("Not submitting `{result}`: {rejection}");
+        return None;
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("Submitting result...");
+    let response = client.submit(puzzle, part, &result);
+
+    match &response {
+        Ok(response) => {
+            println!("{response}");
+
+            submissions.record(puzzle, part, &result, response, unix_now());
+            if let Err(e) = submissions.store_file() {
+                eprintln!("Failed to log submission: {e}");
+            }
+
+            if *response == SubmitResponse::Correct {
+                record_answer(puzzle, part, &result);
+            }
+        }
+        Err(e) => eprintln!("failed to submit result: {e}"),
+    }
+
+    Some(response)
+}
+
+fn record_answer(puzzle: PuzzleId, part: u8, answer: &str) {
+    let mut answers = Answers::read_from_file();
+    answers.set(puzzle, part, answer);
+
+    match answers.store_file() {
+        Ok(()) => println!("🎄 Recorded accepted answer in \"data/answers.json\"."),
+        Err(e) => eprintln!("Failed to record accepted answer: {e}"),
+    }
 }
@@ -0,0 +1,179 @@
+//! Summary statistics for a set of benchmark samples.
+
+use std::time::Duration;
+
+/// Samples with a modified z-score above this value are treated as outliers.
+/// See: Iglewicz and Hoaglin, "How to Detect and Handle Outliers" (1993).
+const OUTLIER_THRESHOLD: f64 = 3.5;
+
+/// Critical value of a two-sided test at a significance level of 5%.
+/// Benches take at least 10 samples, so the normal distribution is used as an approximation.
+const T_CRITICAL: f64 = 1.96;
+
+/// Scales the median absolute deviation to be a consistent estimator of the standard deviation.
+const MAD_SCALE: f64 = 1.4826;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchStats {
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    /// Number of samples taken, including outliers.
+    pub samples: u64,
+    /// Number of samples that were rejected as outliers.
+    pub outliers: u64,
+}
+
+impl BenchStats {
+    /// Computes statistics for a set of samples. Outliers are detected with the median absolute deviation
+    /// and excluded from every statistic except `samples`. Returns `None` if `samples` is empty.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_unstable_by(f64::total_cmp);
+
+        let center = median(&nanos);
+
+        let mut deviations: Vec<f64> = nanos.iter().map(|x| (x - center).abs()).collect();
+        deviations.sort_unstable_by(f64::total_cmp);
+        let mad = median(&deviations) * MAD_SCALE;
+
+        // NOTE: if more than half of the samples are identical, the MAD is zero and every other sample
+        // would be flagged. Skip rejection in that case.
+        let kept: Vec<f64> = if mad > 0.0 {
+            nanos
+                .iter()
+                .copied()
+                .filter(|x| (x - center).abs() / mad <= OUTLIER_THRESHOLD)
+                .collect()
+        } else {
+            nanos.clone()
+        };
+
+        let n = kept.len() as f64;
+        let mean = kept.iter().sum::<f64>() / n;
+
+        let variance = if kept.len() > 1 {
+            kept.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            median: to_duration(median(&kept)),
+            mean: to_duration(mean),
+            stddev: to_duration(variance.sqrt()),
+            p95: to_duration(percentile(&kept, 0.95)),
+            min: to_duration(kept[0]),
+            max: to_duration(kept[kept.len() - 1]),
+            samples: samples.len() as u64,
+            outliers: (nanos.len() - kept.len()) as u64,
+        })
+    }
+}
+
+impl BenchStats {
+    /// Whether the mean execution times of two benches differ significantly, according to Welch's t-test.
+    pub fn differs_significantly(&self, other: &Self) -> bool {
+        let kept = |stats: &Self| stats.samples.saturating_sub(stats.outliers).max(1) as f64;
+        let nanos = |d: Duration| d.as_nanos() as f64;
+
+        let standard_error = (nanos(self.stddev).powi(2) / kept(self)
+            + nanos(other.stddev).powi(2) / kept(other))
+        .sqrt();
+
+        let delta = nanos(self.mean) - nanos(other.mean);
+
+        if standard_error == 0.0 {
+            return delta != 0.0;
+        }
+
+        (delta / standard_error).abs() > T_CRITICAL
+    }
+}
+
+/// Median of a sorted, non-empty slice.
+fn median(sorted: &[f64]) -> f64 {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Nearest-rank percentile of a sorted, non-empty slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_duration(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::BenchStats;
+    use std::time::Duration;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 20, 30, 40, 50])).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.stddev, Duration::from_nanos(16));
+        assert_eq!(stats.p95, Duration::from_nanos(50));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(50));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 11, 9, 10, 12, 10, 1000])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.samples, 7);
+        assert_eq!(stats.max, Duration::from_nanos(12));
+        assert_eq!(stats.median, Duration::from_nanos(10));
+    }
+
+    #[test]
+    fn detects_significant_differences() {
+        let fast =
+            BenchStats::from_samples(&nanos(&[10, 11, 9, 10, 12, 10, 11, 9, 10, 10])).unwrap();
+        let slow =
+            BenchStats::from_samples(&nanos(&[20, 21, 19, 20, 22, 20, 21, 19, 20, 20])).unwrap();
+        let noisy =
+            BenchStats::from_samples(&nanos(&[5, 15, 8, 14, 11, 6, 13, 9, 12, 10])).unwrap();
+
+        assert!(fast.differs_significantly(&slow));
+        assert!(slow.differs_significantly(&fast));
+        assert!(!fast.differs_significantly(&noisy));
+        assert!(!fast.differs_significantly(&fast));
+    }
+
+    #[test]
+    fn keeps_samples_if_deviation_is_zero() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 10, 10, 10, 50])).unwrap();
+        assert_eq!(stats.outliers, 0);
+        assert_eq!(stats.max, Duration::from_nanos(50));
+    }
+}
@@ -0,0 +1,525 @@
+//! A local log of submitted answers, used to avoid submissions that are known to fail.
+
+use std::{collections::HashMap, fmt::Display, fs, io::Error, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::SubmitResponse;
+use crate::template::{Day, PuzzleId, Year};
+
+static SUBMISSIONS_FILE_PATH: &str = "./data/submissions.json";
+
+/// The verdict of the server for a submission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    /// Whether the server judged the answer to be wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl From<&SubmitResponse> for Outcome {
+    fn from(value: &SubmitResponse) -> Self {
+        match value {
+            SubmitResponse::Correct => Outcome::Correct,
+            SubmitResponse::Wrong { .. } => Outcome::Wrong,
+            SubmitResponse::TooHigh { .. } => Outcome::TooHigh,
+            SubmitResponse::TooLow { .. } => Outcome::TooLow,
+            SubmitResponse::RateLimited { .. } => Outcome::RateLimited,
+            SubmitResponse::WrongLevel => Outcome::WrongLevel,
+            SubmitResponse::Unknown(_) => Outcome::Unknown,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Outcome::Correct => "correct",
+            Outcome::Wrong => "wrong",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::RateLimited => "rate_limited",
+            Outcome::WrongLevel => "wrong_level",
+            Outcome::Unknown => "unknown",
+        })
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "wrong" => Ok(Outcome::Wrong),
+            "too_high" => Ok(Outcome::TooHigh),
+            "too_low" => Ok(Outcome::TooLow),
+            "rate_limited" => Ok(Outcome::RateLimited),
+            "wrong_level" => Ok(Outcome::WrongLevel),
+            "unknown" => Ok(Outcome::Unknown),
+            s => Err(format!("Unknown submission outcome `{s}`.")),
+        }
+    }
+}
+
+/// A single submitted answer. Timestamps are seconds since the unix epoch.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Submission {
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    pub submitted_at: u64,
+    /// The server does not accept another answer for this puzzle before this time.
+    pub wait_until: Option<u64>,
+}
+
+/// Reasons for not submitting an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rejection {
+    AlreadySolved {
+        answer: String,
+    },
+    KnownWrong {
+        outcome: Outcome,
+    },
+    /// The answer is at or above an answer that was too high.
+    AboveBound {
+        bound: String,
+    },
+    /// The answer is at or below an answer that was too low.
+    BelowBound {
+        bound: String,
+    },
+    Cooldown {
+        remaining: Duration,
+    },
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::AlreadySolved { answer } => {
+                write!(f, "this part was already solved with `{answer}`.")
+            }
+            Rejection::KnownWrong { outcome } => {
+                write!(
+                    f,
+                    "this answer was already submitted, the verdict was `{outcome}`."
+                )
+            }
+            Rejection::AboveBound { bound } => {
+                write!(f, "answers of `{bound}` or higher are too high.")
+            }
+            Rejection::BelowBound { bound } => {
+                write!(f, "answers of `{bound}` or lower are too low.")
+            }
+            Rejection::Cooldown { remaining } => write!(
+                f,
+                "the server will not accept answers for another {}s.",
+                remaining.as_secs()
+            ),
+        }
+    }
+}
+
+/// Represents all submissions made from this repository.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(SUBMISSIONS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from a JSON file. If not present, returns an empty log.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(SUBMISSIONS_FILE_PATH)
+            .map_err(|x| x.to_string())
+            .and_then(Submissions::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Appends the server's response to a submission to the log.
+    pub fn record(
+        &mut self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+        response: &SubmitResponse,
+        now: u64,
+    ) {
+        let wait = match response {
+            SubmitResponse::Wrong { wait }
+            | SubmitResponse::TooHigh { wait }
+            | SubmitResponse::TooLow { wait } => *wait,
+            SubmitResponse::RateLimited { wait } => Some(*wait),
+            _ => None,
+        };
+
+        self.data.push(Submission {
+            puzzle,
+            part,
+            answer: answer.into(),
+            outcome: Outcome::from(response),
+            submitted_at: now,
+            wait_until: wait.map(|wait| now + wait.as_secs()),
+        });
+    }
+
+    /// Checks whether an answer should be submitted, based on previous submissions.
+    pub fn check(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), Rejection> {
+        let submissions: Vec<&Submission> = self
+            .data
+            .iter()
+            .filter(|s| s.puzzle == puzzle && s.part == part)
+            .collect();
+
+        if let Some(correct) = submissions.iter().find(|s| s.outcome == Outcome::Correct) {
+            return Err(Rejection::AlreadySolved {
+                answer: correct.answer.clone(),
+            });
+        }
+
+        if let Some(known) = submissions
+            .iter()
+            .find(|s| s.answer == answer && s.outcome.is_wrong())
+        {
+            return Err(Rejection::KnownWrong {
+                outcome: known.outcome,
+            });
+        }
+
+        // bounds only apply to numeric answers.
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |outcome: Outcome| {
+                submissions
+                    .iter()
+                    .filter(move |s| s.outcome == outcome)
+                    .filter_map(|s| Some((s.answer.parse::<i128>().ok()?, &s.answer)))
+            };
+
+            if let Some((_, bound)) = bound(Outcome::TooHigh)
+                .filter(|(high, _)| value >= *high)
+                .min()
+            {
+                return Err(Rejection::AboveBound {
+                    bound: bound.clone(),
+                });
+            }
+
+            if let Some((_, bound)) = bound(Outcome::TooLow)
+                .filter(|(low, _)| value <= *low)
+                .max()
+            {
+                return Err(Rejection::BelowBound {
+                    bound: bound.clone(),
+                });
+            }
+        }
+
+        // the cooldown applies to both parts of a puzzle.
+        if let Some(wait_until) = self
+            .data
+            .iter()
+            .filter(|s| s.puzzle == puzzle)
+            .filter_map(|s| s.wait_until)
+            .max()
+            .filter(|wait_until| *wait_until > now)
+        {
+            return Err(Rejection::Cooldown {
+                remaining: Duration::from_secs(wait_until - now),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.puzzle.year.to_string()),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::String(value.puzzle.day.to_string()),
+        );
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "outcome".into(),
+            JsonValue::String(value.outcome.to_string()),
+        );
+        map.insert(
+            "submitted_at".into(),
+            JsonValue::Number(value.submitted_at as f64),
+        );
+        map.insert(
+            "wait_until".into(),
+            value
+                .wait_until
+                .map_or(JsonValue::Null, |x| JsonValue::Number(x as f64)),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected submission.year to be a Year struct.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u8)
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let outcome = json
+            .get("outcome")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.outcome to be a string.")?
+            .parse()?;
+
+        let submitted_at = json
+            .get("submitted_at")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected submission.submitted_at to be a number.")?;
+
+        let wait_until = json
+            .get("wait_until")
+            .map(|v| if v.is_null() { None } else { v.get::<f64>() })
+            .ok_or("Expected submission.wait_until to be null or number.")?;
+
+        Ok(Submission {
+            puzzle: PuzzleId::new(year, day),
+            part,
+            answer: answer.clone(),
+            outcome,
+            submitted_at: *submitted_at as u64,
+            wait_until: wait_until.map(|x| *x as u64),
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Outcome, Rejection, Submissions};
+    use crate::{puzzle, template::aoc_client::SubmitResponse};
+    use std::time::Duration;
+
+    const MINUTE: Option<Duration> = Some(Duration::from_secs(60));
+
+    #[test]
+    fn rejects_known_wrong_answers() {
+        let mut log = Submissions::default();
+        log.record(
+            puzzle!(2023, 1),
+            1,
+            "abc",
+            &SubmitResponse::Wrong { wait: MINUTE },
+            0,
+        );
+
+        assert_eq!(
+            log.check(puzzle!(2023, 1), 1, "abc", 100),
+            Err(Rejection::KnownWrong {
+                outcome: Outcome::Wrong
+            })
+        );
+        assert_eq!(log.check(puzzle!(2023, 1), 1, "abd", 100), Ok(()));
+        assert_eq!(log.check(puzzle!(2023, 1), 2, "abc", 100), Ok(()));
+    }
+
+    #[test]
+    fn rejects_answers_outside_bounds() {
+        let mut log = Submissions::default();
+        log.record(
+            puzzle!(2023, 1),
+            1,
+            "100",
+            &SubmitResponse::TooHigh { wait: None },
+            0,
+        );
+        log.record(
+            puzzle!(2023, 1),
+            1,
+            "80",
+            &SubmitResponse::TooHigh { wait: None },
+            0,
+        );
+        log.record(
+            puzzle!(2023, 1),
+            1,
+            "10",
+            &SubmitResponse::TooLow { wait: None },
+            0,
+        );
+
+        assert_eq!(
+            log.check(puzzle!(2023, 1), 1, "90", 0),
+            Err(Rejection::AboveBound { bound: "80".into() })
+        );
+        assert_eq!(
+            log.check(puzzle!(2023, 1), 1, "-5", 0),
+            Err(Rejection::BelowBound { bound: "10".into() })
+        );
+        assert_eq!(log.check(puzzle!(2023, 1), 1, "79", 0), Ok(()));
+        assert_eq!(log.check(puzzle!(2023, 1), 1, "not a number", 0), Ok(()));
+    }
+
+    #[test]
+    fn tracks_cooldown() {
+        let mut log = Submissions::default();
+        log.record(
+            puzzle!(2023, 1),
+            1,
+            "1",
+            &SubmitResponse::Wrong { wait: MINUTE },
+            1000,
+        );
+        log.record(
+            puzzle!(2023, 1),
+            1,
+            "2",
+            &SubmitResponse::RateLimited {
+                wait: Duration::from_secs(30),
+            },
+            1010,
+        );
+
+        assert_eq!(
+            log.check(puzzle!(2023, 1), 2, "3", 1020),
+            Err(Rejection::Cooldown {
+                remaining: Duration::from_secs(40)
+            })
+        );
+        assert_eq!(log.check(puzzle!(2023, 1), 1, "2", 1060), Ok(()));
+        assert_eq!(log.check(puzzle!(2023, 2), 1, "3", 1020), Ok(()));
+    }
+
+    #[test]
+    fn rejects_solved_parts() {
+        let mut log = Submissions::default();
+        log.record(puzzle!(2023, 1), 1, "42", &SubmitResponse::Correct, 0);
+
+        assert_eq!(
+            log.check(puzzle!(2023, 1), 1, "43", 0),
+            Err(Rejection::AlreadySolved {
+                answer: "42".into()
+            })
+        );
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut log = Submissions::default();
+        log.record(
+            puzzle!(2023, 1),
+            1,
+            "42",
+            &SubmitResponse::TooLow { wait: MINUTE },
+            1_700_000_000,
+        );
+        log.record(
+            puzzle!(2023, 1),
+            2,
+            "43",
+            &SubmitResponse::Correct,
+            1_700_000_100,
+        );
+
+        let json = tinyjson::JsonValue::from(log.clone()).stringify().unwrap();
+        assert_eq!(Submissions::try_from(json).unwrap().data, log.data);
+    }
+}
@@ -1,24 +1,70 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::report::{PartStatus, PARSE_PART};
+use crate::template::stats::BenchStats;
+use crate::template::{Day, PuzzleId, Year};
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
-/// Represents benchmark times for a single day.
+/// Number of benchmark runs that are kept per puzzle part.
+const MAX_HISTORY: usize = 20;
+
+/// Represents benchmark times for a single puzzle.
 #[derive(Clone, Debug)]
 pub struct Timing {
-    pub day: Day,
+    pub puzzle: PuzzleId,
+    /// Execution time of the `parse` function, for solutions that declare one.
+    pub parse: Option<String>,
+    pub parse_stats: Option<BenchStats>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    /// Parts that were not solved are either not implemented yet or failed.
+    pub part_1_status: PartStatus,
+    pub part_2_status: PartStatus,
     pub total_nanos: f64,
 }
 
+/// A stored benchmark run of a single puzzle part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimingRecord {
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    /// Short hash of the commit that was checked out when benching, if known.
+    pub commit: Option<String>,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub stats: BenchStats,
+}
+
+/// The change in execution time of a puzzle part compared to a baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub puzzle: PuzzleId,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Relative change of the median, e.g. `0.1` if the part got 10% slower.
+    pub change: f64,
+    pub is_significant: bool,
+}
+
+impl Comparison {
+    /// Whether the part got significantly slower by more than `threshold`, e.g. `0.1` for 10%.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.is_significant && self.change > threshold
+    }
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
     pub data: Vec<Timing>,
+    /// Past benchmark runs, ordered from oldest to newest.
+    pub history: Vec<TimingRecord>,
 }
 
 impl Timings {
@@ -44,7 +90,70 @@ impl Timings {
         }
     }
 
+    /// Adds the benchmark statistics of `data` to the history.
+    pub fn record_history(&mut self, commit: Option<&str>, timestamp: u64) {
+        for timing in &self.data {
+            let parts = [
+                (PARSE_PART, &timing.parse_stats),
+                (1, &timing.part_1_stats),
+                (2, &timing.part_2_stats),
+            ];
+
+            for (part, stats) in parts {
+                if let Some(stats) = stats {
+                    self.history.push(TimingRecord {
+                        puzzle: timing.puzzle,
+                        part,
+                        commit: commit.map(String::from),
+                        timestamp,
+                        stats: stats.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    /// Compare `current` timings to `self`, for every part that was benched in both.
+    pub fn compare(&self, current: &Self) -> Vec<Comparison> {
+        let mut comparisons = vec![];
+
+        for timing in &current.data {
+            let Some(baseline) = self.data.iter().find(|t| t.puzzle == timing.puzzle) else {
+                continue;
+            };
+
+            let parts = [
+                (PARSE_PART, &baseline.parse_stats, &timing.parse_stats),
+                (1, &baseline.part_1_stats, &timing.part_1_stats),
+                (2, &baseline.part_2_stats, &timing.part_2_stats),
+            ];
+
+            for (part, baseline, current) in parts {
+                if let (Some(baseline), Some(current)) = (baseline, current) {
+                    let before = baseline.median.as_nanos() as f64;
+                    let after = current.median.as_nanos() as f64;
+
+                    comparisons.push(Comparison {
+                        puzzle: timing.puzzle,
+                        part,
+                        baseline: baseline.median,
+                        current: current.median,
+                        change: if before > 0.0 {
+                            after / before - 1.0
+                        } else {
+                            0.0
+                        },
+                        is_significant: current.differs_significantly(baseline),
+                    });
+                }
+            }
+        }
+
+        comparisons
+    }
+
     /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// The history of both is joined, keeping the most recent runs of each part.
     pub fn merge(&self, new: &Self) -> Self {
         let mut data: Vec<Timing> = vec![];
 
@@ -53,13 +162,29 @@ impl Timings {
         }
 
         for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
+            if !data.iter().any(|t| t.puzzle == timing.puzzle) {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
-        Timings { data }
+        data.sort_unstable_by_key(|t| t.puzzle);
+
+        let mut history: Vec<TimingRecord> = vec![];
+
+        // NOTE: walk backwards so that the most recent runs are kept.
+        for record in self.history.iter().chain(&new.history).rev() {
+            let count = history
+                .iter()
+                .filter(|r| r.puzzle == record.puzzle && r.part == record.part)
+                .count();
+
+            if count < MAX_HISTORY {
+                history.push(record.clone());
+            }
+        }
+
+        history.reverse();
+        Timings { data, history }
     }
 
     /// Sum up total duration of timings as millis.
@@ -67,10 +192,10 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
+    pub fn is_day_complete(&self, puzzle: PuzzleId) -> bool {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .any(|t| t.puzzle == puzzle && t.part_1.is_some() && t.part_2.is_some())
     }
 }
 
@@ -85,6 +210,11 @@ impl From<Timings> for JsonValue {
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
         );
 
+        map.insert(
+            "history".into(),
+            JsonValue::Array(value.history.iter().map(JsonValue::from).collect()),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -95,19 +225,33 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let json = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
+        let json_history = match json.get("history") {
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .ok_or("expected `json.history` to be an array.")?
+                .as_slice(),
+            None => &[],
+        };
+
         Ok(Timings {
             data: json_data
                 .iter()
                 .map(Timing::try_from)
                 .collect::<Result<_, _>>()?,
+            history: json_history
+                .iter()
+                .map(TimingRecord::try_from)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -118,9 +262,32 @@ impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
-        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.puzzle.year.to_string()),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::String(value.puzzle.day.to_string()),
+        );
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
+        map.insert(
+            "parse".into(),
+            value
+                .parse
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        map.insert(
+            "parse_stats".into(),
+            value
+                .parse_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
         let part_1 = value.part_1.clone().map(JsonValue::String);
         let part_2 = value.part_2.clone().map(JsonValue::String);
 
@@ -140,6 +307,32 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "part_1_status".into(),
+            JsonValue::String(value.part_1_status.to_string()),
+        );
+
+        map.insert(
+            "part_2_status".into(),
+            JsonValue::String(value.part_2_status.to_string()),
+        );
+
+        map.insert(
+            "part_1_stats".into(),
+            value
+                .part_1_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        map.insert(
+            "part_2_stats".into(),
+            value
+                .part_2_stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -152,6 +345,20 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
+        // NOTE: timings stored by earlier versions of the template only have a `day`, the timings of
+        // both parts and their `total_nanos`. they are read with the default year from `AOC_YEAR`,
+        // every field that was added since then is left empty or derived from the part timings.
+        let is_legacy = !json.contains_key("year");
+
+        let year = match json.get("year") {
+            Some(v) => v
+                .get::<String>()
+                .and_then(|year| Year::from_str(year).ok())
+                .ok_or("Expected timing.year to be a Year struct.")?,
+            None => Year::from_env()
+                .ok_or("Expected timing.year to be set, or a default year in `AOC_YEAR`.")?,
+        };
+
         let day = json
             .get("day")
             .and_then(|v| v.get::<String>())
@@ -173,10 +380,43 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        let field = |key: &str| match json.get(key) {
+            Some(v) => Ok((!v.is_null()).then_some(v)),
+            None if is_legacy => Ok(None),
+            None => Err(format!("Expected timing.{key} to be set.")),
+        };
+
+        let stats = |key: &str| field(key)?.map(BenchStats::try_from).transpose();
+
+        let parse = field("parse")?
+            .map(|v| {
+                v.get::<String>()
+                    .cloned()
+                    .ok_or("Expected timing.parse to be null or string.")
+            })
+            .transpose()?;
+
+        let status = |key: &str, time: Option<&String>| match field(key)? {
+            Some(v) => v
+                .get::<String>()
+                .ok_or(format!("Expected timing.{key} to be a string."))?
+                .parse::<PartStatus>(),
+            None => Ok(match time {
+                Some(_) => PartStatus::Solved,
+                None => PartStatus::Unimplemented,
+            }),
+        };
+
         Ok(Timing {
-            day,
+            puzzle: PuzzleId::new(year, day),
+            parse,
+            parse_stats: stats("parse_stats")?,
+            part_1_status: status("part_1_status", part_1)?,
+            part_2_status: status("part_2_status", part_2)?,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_stats: stats("part_1_stats")?,
+            part_2_stats: stats("part_2_stats")?,
             total_nanos,
         })
     }
@@ -184,9 +424,147 @@ impl TryFrom<&JsonValue> for Timing {
 
 /* -------------------------------------------------------------------------- */
 
+impl From<&TimingRecord> for JsonValue {
+    fn from(value: &TimingRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.puzzle.year.to_string()),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::String(value.puzzle.day.to_string()),
+        );
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "commit".into(),
+            value
+                .commit
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert("stats".into(), JsonValue::from(&value.stats));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for TimingRecord {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing record to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected timing record.year to be a Year struct.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected timing record.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u8)
+            .ok_or("Expected timing record.part to be a number.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected timing record.commit to be null or string.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .ok_or("Expected timing record.timestamp to be a number.")?;
+
+        let stats = json
+            .get("stats")
+            .ok_or("Expected timing record.stats to be present.")?;
+
+        Ok(TimingRecord {
+            puzzle: PuzzleId::new(year, day),
+            part,
+            commit: commit.cloned(),
+            timestamp: *timestamp as u64,
+            stats: BenchStats::try_from(stats)?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let durations = [
+            ("median_nanos", value.median),
+            ("mean_nanos", value.mean),
+            ("stddev_nanos", value.stddev),
+            ("p95_nanos", value.p95),
+            ("min_nanos", value.min),
+            ("max_nanos", value.max),
+        ];
+
+        for (key, duration) in durations {
+            map.insert(key.into(), JsonValue::Number(duration.as_nanos() as f64));
+        }
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|v| v as u64)
+                .ok_or(format!("Expected timing stats.{key} to be a number."))
+        };
+
+        Ok(BenchStats {
+            median: Duration::from_nanos(number("median_nanos")?),
+            mean: Duration::from_nanos(number("mean_nanos")?),
+            stddev: Duration::from_nanos(number("stddev_nanos")?),
+            p95: Duration::from_nanos(number("p95_nanos")?),
+            min: Duration::from_nanos(number("min_nanos")?),
+            max: Duration::from_nanos(number("max_nanos")?),
+            samples: number("samples")?,
+            outliers: number("outliers")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::{puzzle, template::report::PartStatus};
 
     use super::{Timing, Timings};
 
@@ -194,42 +572,88 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2023, 1),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: PartStatus::Solved,
+                    part_2_status: PartStatus::Solved,
                     total_nanos: 3e+10,
                 },
                 Timing {
-                    day: day!(2),
+                    puzzle: puzzle!(2023, 2),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: PartStatus::Solved,
+                    part_2_status: PartStatus::Solved,
                     total_nanos: 7e+10,
                 },
                 Timing {
-                    day: day!(4),
+                    puzzle: puzzle!(2023, 4),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("40ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: PartStatus::Solved,
+                    part_2_status: PartStatus::Unimplemented,
                     total_nanos: 4e+10,
                 },
             ],
+            history: vec![],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day, puzzle,
+            template::{report::PartStatus, timings::Timings, Year},
+        };
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "data": [{ "year": "2023", "day": "01", "parse": null, "parse_stats": null, "part_1": "1ms", "part_2": null, "part_1_status": "solved", "part_2_status": "unimplemented", "part_1_stats": null, "part_2_stats": null, "total_nanos": 1000000000 }], "history": [] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
-            assert_eq!(timing.day, day!(1));
+            assert_eq!(timing.puzzle, puzzle!(2023, 1));
             assert_eq!(timing.part_1, Some("1ms".to_string()));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_part_statuses() {
+            let json = r#"{ "data": [{ "year": "2023", "day": "01", "parse": null, "parse_stats": null, "part_1": null, "part_2": null, "part_1_status": "failed", "part_2_status": "unimplemented", "part_1_stats": null, "part_2_stats": null, "total_nanos": 0 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1_status, PartStatus::Failed);
+            assert_eq!(timing.part_2_status, PartStatus::Unimplemented);
+
+            let json = r#"{ "data": [{ "year": "2023", "day": "01", "parse": null, "parse_stats": null, "part_1": null, "part_2": null, "part_1_status": "failed", "part_1_stats": null, "part_2_stats": null, "total_nanos": 0 }] }"#.to_string();
+            assert!(Timings::try_from(json).is_err());
+        }
+
+        #[test]
+        fn handles_timings_of_earlier_versions() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.puzzle.year, Year::from_env().unwrap());
+            assert_eq!(timing.puzzle.day, day!(1));
+            assert_eq!(timing.parse, None);
+            assert_eq!(timing.part_1_status, PartStatus::Solved);
+            assert_eq!(timing.part_2_status, PartStatus::Unimplemented);
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -247,7 +671,7 @@ mod tests {
         #[test]
         #[should_panic]
         fn panics_for_malformed_timings() {
-            let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            let json = r#"{ "data": [{ "year": "2023", "day": "01" }, { "year": "2023", "day": "26" }, { "year": "2023", "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
             Timings::try_from(json).unwrap();
         }
     }
@@ -277,57 +701,84 @@ mod tests {
 
     mod is_day_complete {
         use crate::{
-            day,
-            template::timings::{Timing, Timings},
+            puzzle,
+            template::{
+                report::PartStatus,
+                timings::{Timing, Timings},
+            },
         };
 
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2023, 1),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: PartStatus::Solved,
+                    part_2_status: PartStatus::Solved,
                     total_nanos: 3_000_000_000_f64,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(puzzle!(2023, 1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2023, 1),
+                    parse: None,
+                    parse_stats: None,
                     part_1: Some("1ms".into()),
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: PartStatus::Solved,
+                    part_2_status: PartStatus::Unimplemented,
                     total_nanos: 1_000_000_000_f64,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(puzzle!(2023, 1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: puzzle!(2023, 1),
+                    parse: None,
+                    parse_stats: None,
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: PartStatus::Unimplemented,
+                    part_2_status: PartStatus::Unimplemented,
                     total_nanos: 0.0,
                 }],
+                history: vec![],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(puzzle!(2023, 1)), false);
         }
     }
 
     mod merge {
         use crate::{
-            day,
-            template::timings::{Timing, Timings},
+            puzzle,
+            template::{
+                report::PartStatus,
+                timings::{Timing, Timings},
+            },
         };
 
         use super::get_mock_timings;
@@ -337,18 +788,25 @@ mod tests {
             let timings = get_mock_timings();
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(3),
+                    puzzle: puzzle!(2023, 3),
+                    parse: None,
+                    parse_stats: None,
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: PartStatus::Unimplemented,
+                    part_2_status: PartStatus::Unimplemented,
                     total_nanos: 0_f64,
                 }],
+                history: vec![],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[2].day, day!(3));
-            assert_eq!(merged.data[3].day, day!(4));
+            assert_eq!(merged.data[0].puzzle, puzzle!(2023, 1));
+            assert_eq!(merged.data[1].puzzle, puzzle!(2023, 2));
+            assert_eq!(merged.data[2].puzzle, puzzle!(2023, 3));
+            assert_eq!(merged.data[3].puzzle, puzzle!(2023, 4));
         }
 
         #[test]
@@ -357,19 +815,26 @@ mod tests {
 
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(2),
+                    puzzle: puzzle!(2023, 2),
+                    parse: None,
+                    parse_stats: None,
                     part_1: None,
                     part_2: None,
+                    part_1_stats: None,
+                    part_2_stats: None,
+                    part_1_status: PartStatus::Unimplemented,
+                    part_2_status: PartStatus::Unimplemented,
                     total_nanos: 0_f64,
                 }],
+                history: vec![],
             };
             let merged = timings.merge(&other);
 
             assert_eq!(merged.data.len(), 3);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[0].puzzle, puzzle!(2023, 1));
+            assert_eq!(merged.data[1].puzzle, puzzle!(2023, 2));
             assert_eq!(merged.data[1].total_nanos, 0_f64);
-            assert_eq!(merged.data[2].day, day!(4));
+            assert_eq!(merged.data[2].puzzle, puzzle!(2023, 4));
         }
 
         #[test]
@@ -388,4 +853,109 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
         }
     }
+
+    mod history {
+        use std::time::Duration;
+
+        use crate::{
+            puzzle,
+            template::{
+                report::PartStatus,
+                stats::BenchStats,
+                timings::{Timing, Timings, MAX_HISTORY},
+                PuzzleId,
+            },
+        };
+
+        fn stats(nanos: &[u64]) -> Option<BenchStats> {
+            let samples: Vec<Duration> = nanos.iter().map(|x| Duration::from_nanos(*x)).collect();
+            BenchStats::from_samples(&samples)
+        }
+
+        fn timings(puzzle: PuzzleId, part_1: &[u64], part_2: &[u64]) -> Timings {
+            Timings {
+                data: vec![Timing {
+                    puzzle,
+                    parse: None,
+                    parse_stats: None,
+                    part_1: Some("-".into()),
+                    part_2: Some("-".into()),
+                    part_1_stats: stats(part_1),
+                    part_2_stats: stats(part_2),
+                    part_1_status: PartStatus::Solved,
+                    part_2_status: PartStatus::Solved,
+                    total_nanos: 0_f64,
+                }],
+                history: vec![],
+            }
+        }
+
+        #[test]
+        fn records_history() {
+            let mut timings = timings(puzzle!(2023, 1), &[10, 10], &[20, 20]);
+            timings.record_history(Some("abc1234"), 1_700_000_000);
+
+            assert_eq!(timings.history.len(), 2);
+            assert_eq!(timings.history[0].part, 1);
+            assert_eq!(timings.history[1].part, 2);
+            assert_eq!(timings.history[1].commit.as_deref(), Some("abc1234"));
+            assert_eq!(timings.history[1].timestamp, 1_700_000_000);
+        }
+
+        #[test]
+        fn bounds_history() {
+            let mut stored = Timings::default();
+
+            for timestamp in 0..(MAX_HISTORY as u64 + 5) {
+                let mut new = timings(puzzle!(2023, 1), &[10, 10], &[20, 20]);
+                new.record_history(None, timestamp);
+                stored = stored.merge(&new);
+            }
+
+            assert_eq!(stored.history.len(), MAX_HISTORY * 2);
+            assert_eq!(stored.history.first().unwrap().timestamp, 5);
+            assert_eq!(
+                stored.history.last().unwrap().timestamp,
+                MAX_HISTORY as u64 + 4
+            );
+        }
+
+        #[test]
+        fn roundtrips_history() {
+            let mut timings = timings(puzzle!(2023, 1), &[10, 12], &[20, 21]);
+            timings.record_history(Some("abc1234"), 1_700_000_000);
+
+            let json = tinyjson::JsonValue::from(timings.clone())
+                .stringify()
+                .unwrap();
+            assert_eq!(Timings::try_from(json).unwrap().history, timings.history);
+        }
+
+        #[test]
+        fn compares_timings() {
+            let baseline = timings(
+                puzzle!(2023, 1),
+                &[100, 101, 99, 100, 102, 100, 101, 99, 100, 100],
+                &[50, 40, 60, 45, 55, 50, 52, 48, 50, 50],
+            );
+            let current = timings(
+                puzzle!(2023, 1),
+                &[120, 121, 119, 120, 122, 120, 121, 119, 120, 120],
+                &[52, 42, 62, 47, 57, 52, 54, 50, 52, 52],
+            );
+
+            let comparisons = baseline.compare(&current);
+            assert_eq!(comparisons.len(), 2);
+
+            assert_eq!(comparisons[0].part, 1);
+            assert!((comparisons[0].change - 0.2).abs() < 1e-9);
+            assert!(comparisons[0].is_significant);
+            assert!(comparisons[0].is_regression(0.1));
+            assert!(!comparisons[0].is_regression(0.25));
+
+            assert_eq!(comparisons[1].part, 2);
+            assert!(!comparisons[1].is_significant);
+            assert!(!comparisons[1].is_regression(0.0));
+        }
+    }
 }